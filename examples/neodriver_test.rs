@@ -1,7 +1,9 @@
 #![no_std]
 #![no_main]
 #![allow(unused_imports, dead_code)]
-use adafruit_seesaw::{prelude::*, seesaw_device, Driver, SeesawDriver};
+#[cfg(feature = "device_neodriver")]
+use adafruit_seesaw::devices::NeoDriver;
+use adafruit_seesaw::{prelude::*, SeesawDriver};
 use cortex_m_rt::entry;
 use rtt_target::{rprintln, rtt_init_print};
 use stm32f4xx_hal::{
@@ -12,32 +14,17 @@ use stm32f4xx_hal::{
     rcc::{RccExt, SYSCLK_MAX},
 };
 
-seesaw_device! {
-    name: NeoDriver,
-    hardware_id: HardwareId::ATTINY817,
-    product_id: 5766,
-    default_addr: 0x60
-}
-
 const N_LEDS: usize = 50;
 
-#[cfg(feature = "module_neopixel")]
-impl<D: Driver> NeopixelModule<D> for NeoDriver<D> {
-    type Color = rgb::Grb<u8>;
-
-    const N_LEDS: usize = N_LEDS;
-    const PIN: u8 = 15;
-}
-
-#[cfg(not(feature = "module_neopixel"))]
+#[cfg(not(feature = "device_neodriver"))]
 #[entry]
 fn main() -> ! {
-    rprintln!("NeoDriver module not enabled");
+    rprintln!("NeoDriver device not enabled");
     #[allow(clippy::empty_loop)]
     loop {}
 }
 
-#[cfg(feature = "module_neopixel")]
+#[cfg(feature = "device_neodriver")]
 #[entry]
 fn main() -> ! {
     rtt_init_print!();
@@ -51,16 +38,15 @@ fn main() -> ! {
     let sda = gpiob.pb7.into_alternate_open_drain::<4>();
     let i2c = I2c::new(dp.I2C1, (scl, sda), 400.kHz(), &clocks);
     let seesaw = SeesawDriver::new(delay, i2c);
-    let mut neo_driver = NeoDriver::new_with_default_addr(seesaw);
-    neo_driver
-        .reset_and_verify_seesaw()
-        .and_then(|_| neo_driver.enable_neopixel())
+    let mut neo_driver = NeoDriver::new_with_default_addr(seesaw)
+        .with_len(N_LEDS)
+        .init()
         .expect("Failed to init NeoDriver");
 
     let id = neo_driver.hardware_id().expect("Failed to get hardware id");
     rprintln!("Hardware ID {:?}", id);
 
-    let mut colors: [rgb::Grb<u8>; N_LEDS] =
+    let mut colors: [rgb::RGB8; N_LEDS] =
         core::array::from_fn(|i| color_wheel((i as u8).wrapping_mul(255 / N_LEDS as u8)));
 
     loop {
@@ -87,19 +73,19 @@ fn handle_panic(info: &core::panic::PanicInfo) -> ! {
     loop {}
 }
 
-const fn color_wheel(byte: u8) -> rgb::Grb<u8> {
+const fn color_wheel(byte: u8) -> rgb::RGB8 {
     match byte {
-        0..=84 => rgb::Grb {
+        0..=84 => rgb::RGB8 {
             r: 255 - byte * 3,
             g: 0,
             b: byte * 3,
         },
-        85..=169 => rgb::Grb {
+        85..=169 => rgb::RGB8 {
             r: 0,
             g: (byte - 85) * 3,
             b: 255 - (byte - 85) * 3,
         },
-        _ => rgb::Grb {
+        _ => rgb::RGB8 {
             r: (byte - 170) * 3,
             g: 255 - (byte - 170) * 3,
             b: 0,
@@ -0,0 +1,54 @@
+#![no_std]
+#![no_main]
+use adafruit_seesaw::{
+    devices::{MoistureCalibration, SoilSensor},
+    prelude::*,
+    SeesawDriver,
+};
+use cortex_m_rt::entry;
+use rtt_target::{rprintln, rtt_init_print};
+use stm32f4xx_hal::{gpio::GpioExt, i2c::I2c, pac, prelude::*, rcc::RccExt};
+
+#[entry]
+fn main() -> ! {
+    rtt_init_print!();
+    let cp = cortex_m::Peripherals::take().unwrap();
+    let dp = pac::Peripherals::take().unwrap();
+    let gpiob = dp.GPIOB.split();
+    let clocks = dp.RCC.constrain().cfgr.freeze();
+    let delay = cp.SYST.delay(&clocks);
+    let scl = gpiob.pb6.into_alternate_open_drain::<4>();
+    let sda = gpiob.pb7.into_alternate_open_drain::<4>();
+    let i2c = I2c::new(dp.I2C1, (scl, sda), 100.kHz(), &clocks);
+    let seesaw = SeesawDriver::new(delay, i2c);
+    let mut sensor = SoilSensor::new_with_default_addr(seesaw)
+        .init()
+        .expect("Failed to start SoilSensor");
+    let calibration = MoistureCalibration::default();
+
+    loop {
+        let moisture = sensor.moisture().expect("Failed to read moisture");
+        let temperature = sensor.temperature().expect("Failed to read temperature");
+        rprintln!(
+            "Moisture: {} ({}%), temperature: {}°C",
+            moisture,
+            calibration.percent(moisture),
+            temperature
+        );
+    }
+}
+
+#[panic_handler]
+fn handle_panic(info: &core::panic::PanicInfo) -> ! {
+    rprintln!("PANIC! {}", info.message());
+    if let Some(location) = info.location() {
+        rprintln!(
+            "Panic occurred in file '{}' at line {}",
+            location.file(),
+            location.line(),
+        );
+    } else {
+        rprintln!("Panic occurred but can't get location information...");
+    }
+    loop {}
+}
@@ -0,0 +1,136 @@
+use crate::{driver::DELAY_TIME, modules::Reg, SeesawDriver};
+use embedded_hal::i2c::{Operation, SevenBitAddress};
+use embedded_hal_async::{delay::DelayNs, i2c::I2c};
+
+impl<I2C, DELAY> I2c for SeesawDriver<I2C, DELAY>
+where
+    DELAY: DelayNs,
+    I2C: I2c,
+{
+    async fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.1.transaction(address, operations).await
+    }
+}
+
+impl<I2C, DELAY> DelayNs for SeesawDriver<I2C, DELAY>
+where
+    DELAY: DelayNs,
+    I2C: I2c,
+{
+    async fn delay_ns(&mut self, ns: u32) {
+        self.0.delay_ns(ns).await
+    }
+}
+
+/// Blanket trait for anything that implements async I2C and an async delay
+pub trait AsyncDriver: I2c + DelayNs {}
+impl<T> AsyncDriver for T where T: I2c + DelayNs {}
+
+macro_rules! impl_integer_write {
+    ($fn:ident $nty:tt) => {
+        async fn $fn(
+            &mut self,
+            addr: SevenBitAddress,
+            reg: &Reg,
+            value: $nty,
+        ) -> Result<(), Self::Error> {
+            self.register_write(addr, reg, &<$nty>::to_be_bytes(value))
+                .await
+        }
+    };
+}
+
+macro_rules! impl_integer_read {
+    ($fn:ident $nty:tt) => {
+        async fn $fn(&mut self, addr: SevenBitAddress, reg: &Reg) -> Result<$nty, Self::Error> {
+            self.register_read::<{ ($nty::BITS / 8) as usize }>(addr, reg)
+                .await
+                .map($nty::from_be_bytes)
+        }
+    };
+}
+
+/// The async twin of [`DriverExt`](crate::DriverExt)
+#[allow(async_fn_in_trait)]
+pub trait AsyncDriverExt {
+    type Error;
+
+    async fn register_read<const N: usize>(
+        &mut self,
+        addr: SevenBitAddress,
+        reg: &Reg,
+    ) -> Result<[u8; N], Self::Error>;
+
+    /// Read as many bytes as fit in `buffer` from a register
+    async fn register_read_into(
+        &mut self,
+        addr: SevenBitAddress,
+        reg: &Reg,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>;
+
+    async fn register_write(
+        &mut self,
+        addr: SevenBitAddress,
+        reg: &Reg,
+        bytes: &[u8],
+    ) -> Result<(), Self::Error>;
+
+    impl_integer_read! { read_u8 u8 }
+    impl_integer_read! { read_u16 u16 }
+    impl_integer_read! { read_u32 u32 }
+    impl_integer_read! { read_u64 u64 }
+    impl_integer_read! { read_i8 i8 }
+    impl_integer_read! { read_i16 i16 }
+    impl_integer_read! { read_i32 i32 }
+    impl_integer_read! { read_i64 i64 }
+    impl_integer_write! { write_u8 u8 }
+    impl_integer_write! { write_u16 u16 }
+    impl_integer_write! { write_u32 u32 }
+    impl_integer_write! { write_u64 u64 }
+    impl_integer_write! { write_i8 i8 }
+    impl_integer_write! { write_i16 i16 }
+    impl_integer_write! { write_i32 i32 }
+    impl_integer_write! { write_i64 i64 }
+}
+
+impl<T: AsyncDriver> AsyncDriverExt for T {
+    type Error = T::Error;
+
+    async fn register_read<const N: usize>(
+        &mut self,
+        addr: SevenBitAddress,
+        reg: &Reg,
+    ) -> Result<[u8; N], Self::Error> {
+        let mut buffer = [0u8; N];
+        self.register_read_into(addr, reg, &mut buffer).await?;
+        Ok(buffer)
+    }
+
+    async fn register_read_into(
+        &mut self,
+        addr: SevenBitAddress,
+        reg: &Reg,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.write(addr, reg).await?;
+        self.delay_us(DELAY_TIME).await;
+        self.read(addr, buffer).await
+    }
+
+    async fn register_write(
+        &mut self,
+        addr: SevenBitAddress,
+        reg: &Reg,
+        bytes: &[u8],
+    ) -> Result<(), Self::Error> {
+        self.transaction(addr, &mut [Operation::Write(reg), Operation::Write(bytes)])
+            .await?;
+        self.delay_us(DELAY_TIME).await;
+        Ok(())
+    }
+}
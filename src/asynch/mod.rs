@@ -0,0 +1,102 @@
+//! Async counterparts of the driver, module and device traits, built on
+//! [`embedded-hal-async`](https://docs.rs/embedded-hal-async).
+//!
+//! The async traits mirror their blocking twins method-for-method and are
+//! implemented by the same device types, e.g. a `NeoKey1x4<D>` whose driver
+//! implements `embedded_hal_async::i2c::I2c` and
+//! `embedded_hal_async::delay::DelayNs` gets [`AsyncGpioModule`] and
+//! [`AsyncNeopixelModule`].
+//!
+//! The async traits are kept out of the crate's main prelude. Many HALs
+//! implement both the blocking and async I2C traits on the same type, and
+//! having both sets of traits in scope would make every method call
+//! ambiguous. Import [`prelude`] instead.
+use crate::{devices::SeesawDevice, SeesawError};
+
+mod driver;
+pub mod modules;
+pub use driver::*;
+
+pub mod prelude {
+    #[cfg(feature = "module_adc")]
+    pub use super::modules::adc::*;
+    #[cfg(feature = "module_eeprom")]
+    pub use super::modules::eeprom::*;
+    #[cfg(feature = "module_encoder")]
+    pub use super::modules::encoder::*;
+    #[cfg(feature = "module_gpio")]
+    pub use super::modules::gpio::*;
+    #[cfg(feature = "module_keypad")]
+    pub use super::modules::keypad::*;
+    #[cfg(feature = "module_neopixel")]
+    pub use super::modules::neopixel::*;
+    #[cfg(feature = "module_timer")]
+    pub use super::modules::timer::*;
+    #[cfg(feature = "module_touch")]
+    pub use super::modules::touch::*;
+    #[cfg(feature = "module_gpio")]
+    pub use super::AsyncButtonInterrupts;
+    pub use super::{modules::status::*, AsyncDriver, AsyncDriverExt, AsyncSeesawDeviceInit};
+    pub use crate::{devices::SeesawDevice, modules::HardwareId, SeesawDriver};
+}
+
+#[cfg(feature = "module_adc")]
+pub use modules::adc::AsyncAdcModule;
+#[cfg(feature = "module_eeprom")]
+pub use modules::eeprom::AsyncEepromModule;
+#[cfg(feature = "module_encoder")]
+pub use modules::encoder::AsyncEncoderModule;
+#[cfg(feature = "module_gpio")]
+pub use modules::gpio::AsyncGpioModule;
+#[cfg(feature = "module_keypad")]
+pub use modules::keypad::AsyncKeypadModule;
+#[cfg(feature = "module_neopixel")]
+pub use modules::neopixel::AsyncNeopixelModule;
+pub use modules::status::AsyncStatusModule;
+#[cfg(feature = "module_timer")]
+pub use modules::timer::AsyncTimerModule;
+#[cfg(feature = "module_touch")]
+pub use modules::touch::AsyncTouchModule;
+
+/// All devices implement the async status module
+impl<D: AsyncDriver, T: SeesawDevice<Driver = D>> AsyncStatusModule<D> for T {}
+
+/// The async twin of [`SeesawDeviceInit`](crate::devices::SeesawDeviceInit)
+#[allow(async_fn_in_trait)]
+pub trait AsyncSeesawDeviceInit<D: AsyncDriver>: SeesawDevice<Driver = D>
+where
+    Self: Sized,
+{
+    async fn init(self) -> Result<Self, SeesawError<D::Error>>;
+}
+
+/// The async twin of the `init_with_interrupts` and `enable_button_interrupts`
+/// methods of devices with buttons, like the `NeoKey1x4` and
+/// `ArcadeButton1x4`
+///
+/// These are inherent methods on the blocking devices. Async ones would clash
+/// with them, so they're provided by this trait instead.
+#[cfg(feature = "module_gpio")]
+#[allow(async_fn_in_trait)]
+pub trait AsyncButtonInterrupts<D: AsyncDriver>:
+    AsyncGpioModule<D> + AsyncSeesawDeviceInit<D>
+{
+    /// The pins of the device's buttons, as a bitmask
+    const BUTTON_MASK: u32;
+
+    /// Enable pin-change interrupts on the buttons, so the IRQ pin is asserted
+    /// whenever a button is pressed or released
+    async fn enable_button_interrupts(&mut self) -> Result<(), SeesawError<D::Error>> {
+        self.enable_pin_interrupt_bulk(Self::BUTTON_MASK).await
+    }
+
+    /// Same as [`init`](AsyncSeesawDeviceInit::init), but also enables the
+    /// interrupts on the buttons
+    async fn init_with_interrupts(self) -> Result<Self, SeesawError<D::Error>> {
+        let mut device = self.init().await?;
+        device.enable_button_interrupts().await?;
+        // Clear any changes latched while the pins were being configured
+        device.read_pin_interrupt_flags().await?;
+        Ok(device)
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{
+    asynch::{AsyncDriver, AsyncDriverExt},
+    devices::SeesawDevice,
+    modules::adc::{adc_channel, CHANNEL_0},
+    SeesawError,
+};
+
+/// The async twin of [`AdcModule`](crate::modules::adc::AdcModule)
+#[allow(async_fn_in_trait)]
+pub trait AsyncAdcModule<D: AsyncDriver>: SeesawDevice<Driver = D> {
+    /// Read the analog value on an ADC-enabled pin.
+    ///
+    /// On the SAMD09 breakout, the pin corresponds to the number on the
+    /// silkscreen. On the default seesaw firmware on the SAMD09 breakout, pins
+    /// 2, 3, and 4 are ADC-enabled.
+    async fn analog_read(&mut self, pin: u8) -> Result<u16, SeesawError<D::Error>> {
+        let pin_offset = adc_channel(Self::HARDWARE_ID, pin);
+        let addr = self.addr();
+        self.driver()
+            .read_u16(addr, &[CHANNEL_0[0], CHANNEL_0[1] + pin_offset])
+            .await
+            .map_err(SeesawError::I2c)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    asynch::{AsyncDriver, AsyncDriverExt, AsyncStatusModule},
+    devices::SeesawDevice,
+    modules::eeprom::{check_addr, check_bounds, eeprom_reg, READ_CHUNK, WRITE_CHUNK},
+    SeesawError,
+};
+
+/// The async twin of [`EepromModule`](crate::modules::eeprom::EepromModule)
+#[allow(async_fn_in_trait)]
+pub trait AsyncEepromModule<D: AsyncDriver>: SeesawDevice<Driver = D> {
+    /// The size of the device's EEPROM in bytes
+    const EEPROM_SIZE: usize = Self::HARDWARE_ID.eeprom_size();
+
+    /// Persistently change the device's I2C address
+    ///
+    /// See [`EepromModule::change_addr`](crate::modules::eeprom::EepromModule::change_addr).
+    async fn change_addr(&mut self, addr: u8) -> Result<(), SeesawError<D::Error>>
+    where
+        Self: Sized,
+    {
+        check_addr(addr)?;
+        let reg = eeprom_reg((Self::EEPROM_SIZE - 1) as u8);
+        let old_addr = self.addr();
+        self.driver()
+            .register_write(old_addr, &reg, &[addr])
+            .await
+            .map_err(SeesawError::I2c)?;
+        let reset = self.reset().await;
+        self.set_addr(addr);
+        reset?;
+        match self.hardware_id().await? {
+            id if id == Self::HARDWARE_ID.into() => Ok(()),
+            id => Err(SeesawError::InvalidHardwareId(id)),
+        }
+    }
+
+    /// Read the byte at `offset`
+    async fn eeprom_read(&mut self, offset: u8) -> Result<u8, SeesawError<D::Error>> {
+        check_bounds(offset, 1, Self::EEPROM_SIZE)?;
+        let addr = self.addr();
+        self.driver()
+            .read_u8(addr, &eeprom_reg(offset))
+            .await
+            .map_err(SeesawError::I2c)
+    }
+
+    /// Fill `buf` with the bytes starting at `offset`
+    async fn eeprom_read_slice(
+        &mut self,
+        offset: u8,
+        buf: &mut [u8],
+    ) -> Result<(), SeesawError<D::Error>> {
+        check_bounds(offset, buf.len(), Self::EEPROM_SIZE)?;
+        let addr = self.addr();
+        for (i, chunk) in buf.chunks_mut(READ_CHUNK).enumerate() {
+            let reg = eeprom_reg(offset + (i * READ_CHUNK) as u8);
+            self.driver()
+                .register_read_into(addr, &reg, chunk)
+                .await
+                .map_err(SeesawError::I2c)?;
+        }
+        Ok(())
+    }
+
+    /// Write the byte at `offset`
+    async fn eeprom_write(&mut self, offset: u8, value: u8) -> Result<(), SeesawError<D::Error>> {
+        self.eeprom_write_slice(offset, &[value]).await
+    }
+
+    /// Write `data` starting at `offset`
+    ///
+    /// See [`EepromModule::eeprom_write_slice`](crate::modules::eeprom::EepromModule::eeprom_write_slice).
+    async fn eeprom_write_slice(
+        &mut self,
+        offset: u8,
+        data: &[u8],
+    ) -> Result<(), SeesawError<D::Error>> {
+        check_bounds(offset, data.len(), Self::EEPROM_SIZE - 1)?;
+        let addr = self.addr();
+        for (i, chunk) in data.chunks(WRITE_CHUNK).enumerate() {
+            let reg = eeprom_reg(offset + (i * WRITE_CHUNK) as u8);
+            self.driver()
+                .register_write(addr, &reg, chunk)
+                .await
+                .map_err(SeesawError::I2c)?;
+        }
+        Ok(())
+    }
+}
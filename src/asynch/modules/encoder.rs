@@ -0,0 +1,88 @@
+use super::gpio::AsyncGpioModule;
+use crate::{
+    asynch::{AsyncDriver, AsyncDriverExt},
+    modules::{
+        encoder::{DELTA, INT_CLR, INT_SET, POSITION},
+        gpio::{pin_mask, Button, PinMode},
+    },
+    SeesawError,
+};
+
+/// The async twin of [`EncoderModule`](crate::modules::encoder::EncoderModule)
+#[allow(async_fn_in_trait)]
+pub trait AsyncEncoderModule<D: AsyncDriver, const N_ENCODERS: usize>: AsyncGpioModule<D> {
+    const ENCODER_BTN_PINS: [u8; N_ENCODERS];
+
+    async fn enable_button(&mut self, encoder: usize) -> Result<(), SeesawError<D::Error>> {
+        self.set_pin_mode(Self::ENCODER_BTN_PINS[encoder], PinMode::InputPullup)
+            .await?;
+        self.driver().delay_us(125).await;
+        Ok(())
+    }
+
+    async fn enable_buttons(&mut self) -> Result<(), SeesawError<D::Error>> {
+        self.set_pin_mode_bulk(pin_mask(&Self::ENCODER_BTN_PINS), PinMode::InputPullup)
+            .await?;
+        self.driver().delay_us(125).await;
+        Ok(())
+    }
+
+    async fn button(&mut self, encoder: usize) -> Result<bool, SeesawError<D::Error>> {
+        self.read_button(Button::active_low(Self::ENCODER_BTN_PINS[encoder]))
+            .await
+    }
+
+    async fn buttons(&mut self) -> Result<[bool; N_ENCODERS], SeesawError<D::Error>> {
+        self.read_buttons(&Self::ENCODER_BTN_PINS.map(Button::active_low))
+            .await
+    }
+
+    async fn delta(&mut self, encoder: usize) -> Result<i32, SeesawError<D::Error>> {
+        let addr = self.addr();
+        let reg = &[DELTA[0], DELTA[1] | encoder as u8];
+        self.driver()
+            .read_i32(addr, reg)
+            .await
+            .map_err(SeesawError::I2c)
+    }
+
+    async fn disable_interrupt(&mut self, encoder: usize) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+        let reg = &[INT_CLR[0], INT_CLR[1] | encoder as u8];
+        self.driver()
+            .write_u8(addr, reg, 1)
+            .await
+            .map_err(SeesawError::I2c)
+    }
+
+    async fn enable_interrupt(&mut self, encoder: usize) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+        let reg = &[INT_SET[0], INT_SET[1] | encoder as u8];
+        self.driver()
+            .write_u8(addr, reg, 1)
+            .await
+            .map_err(SeesawError::I2c)
+    }
+
+    async fn position(&mut self, encoder: usize) -> Result<i32, SeesawError<D::Error>> {
+        let addr = self.addr();
+        let reg = &[POSITION[0], POSITION[1] | encoder as u8];
+        self.driver()
+            .read_i32(addr, reg)
+            .await
+            .map_err(SeesawError::I2c)
+    }
+
+    async fn set_position(
+        &mut self,
+        encoder: usize,
+        pos: i32,
+    ) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+        let reg = &[POSITION[0], POSITION[1] | encoder as u8];
+        self.driver()
+            .write_i32(addr, reg, pos)
+            .await
+            .map_err(SeesawError::I2c)
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    asynch::{AsyncDriver, AsyncDriverExt},
+    devices::SeesawDevice,
+    modules::{
+        gpio::{
+            Button, PinMode, PinOutput, GPIO, INT_DISABLE, INT_ENABLE, INT_FLAG, PULL_DISABLE,
+            PULL_ENABLE, SET_HIGH, SET_INPUT, SET_LOW, SET_OUTPUT, TOGGLE,
+        },
+        Reg,
+    },
+    SeesawError,
+};
+
+/// The async twin of [`GpioModule`](crate::modules::gpio::GpioModule)
+#[allow(async_fn_in_trait)]
+pub trait AsyncGpioModule<D: AsyncDriver>: SeesawDevice<Driver = D> {
+    async fn digital_read(&mut self, pin: u8) -> Result<bool, SeesawError<D::Error>> {
+        self.digital_read_bulk()
+            .await
+            .map(|pins| (pins >> pin) & 1 == 1)
+    }
+
+    async fn digital_read_bulk(&mut self) -> Result<u32, SeesawError<D::Error>> {
+        let addr = self.addr();
+        self.driver()
+            .read_u32(addr, GPIO)
+            .await
+            .map_err(SeesawError::I2c)
+    }
+
+    async fn read_button(&mut self, button: Button) -> Result<bool, SeesawError<D::Error>> {
+        self.digital_read_bulk()
+            .await
+            .map(|pins| button.is_pressed(pins))
+    }
+
+    async fn read_buttons<const N: usize>(
+        &mut self,
+        buttons: &[Button; N],
+    ) -> Result<[bool; N], SeesawError<D::Error>> {
+        self.digital_read_bulk()
+            .await
+            .map(|pins| buttons.map(|button| button.is_pressed(pins)))
+    }
+
+    async fn digital_write(
+        &mut self,
+        pin: u8,
+        output: PinOutput,
+    ) -> Result<(), SeesawError<D::Error>> {
+        self.digital_write_bulk(1 << pin, output).await
+    }
+
+    async fn digital_write_bulk(
+        &mut self,
+        pins: u32,
+        output: PinOutput,
+    ) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+        let bus = self.driver();
+
+        match output {
+            PinOutput::High => bus.write_u32(addr, SET_HIGH, pins).await,
+            PinOutput::Low => bus.write_u32(addr, SET_LOW, pins).await,
+            PinOutput::Set => bus.write_u32(addr, GPIO, pins).await,
+            PinOutput::Toggle => bus.write_u32(addr, TOGGLE, pins).await,
+        }
+        .map_err(SeesawError::I2c)
+    }
+
+    /// Disable the pin-change interrupt on a pin
+    async fn disable_pin_interrupt(&mut self, pin: u8) -> Result<(), SeesawError<D::Error>> {
+        self.disable_pin_interrupt_bulk(1 << pin).await
+    }
+
+    /// Disable the pin-change interrupts on every pin set in `pins`
+    async fn disable_pin_interrupt_bulk(&mut self, pins: u32) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+        self.driver()
+            .write_u32(addr, INT_DISABLE, pins)
+            .await
+            .map_err(SeesawError::I2c)
+    }
+
+    /// Disable the pull-up or pull-down resistor on a pin
+    async fn disable_pull(&mut self, pin: u8) -> Result<(), SeesawError<D::Error>> {
+        self.disable_pull_bulk(1 << pin).await
+    }
+
+    /// Disable the pull-up or pull-down resistors on every pin set in `pins`
+    async fn disable_pull_bulk(&mut self, pins: u32) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+        self.driver()
+            .write_u32(addr, PULL_DISABLE, pins)
+            .await
+            .map_err(SeesawError::I2c)
+    }
+
+    /// Enable the pin-change interrupt on a pin. Whenever the pin's value
+    /// changes, its bit is set in the interrupt flags and the IRQ pin is
+    /// asserted until the flags are read.
+    async fn enable_pin_interrupt(&mut self, pin: u8) -> Result<(), SeesawError<D::Error>> {
+        self.enable_pin_interrupt_bulk(1 << pin).await
+    }
+
+    /// Enable the pin-change interrupts on every pin set in `pins`
+    async fn enable_pin_interrupt_bulk(&mut self, pins: u32) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+        self.driver()
+            .write_u32(addr, INT_ENABLE, pins)
+            .await
+            .map_err(SeesawError::I2c)
+    }
+
+    /// Read the interrupt flags of all pins. A set bit means the
+    /// corresponding pin has changed since the flags were last read.
+    ///
+    /// Reading the flags clears them and releases the IRQ pin.
+    async fn read_pin_interrupt_flags(&mut self) -> Result<u32, SeesawError<D::Error>> {
+        let addr = self.addr();
+        self.driver()
+            .read_u32(addr, INT_FLAG)
+            .await
+            .map_err(SeesawError::I2c)
+    }
+
+    async fn set_pin_mode(&mut self, pin: u8, mode: PinMode) -> Result<(), SeesawError<D::Error>> {
+        self.set_pin_mode_bulk(1 << pin, mode).await
+    }
+
+    /// Set the mode of every pin set in `pins`
+    ///
+    /// `Pullup` and `Pulldown` enable the pull resistor without changing the
+    /// pins' direction. The seesaw firmware has no way to configure open-drain
+    /// outputs or pin function muxing, so `OpenDrain`, `OutputOpenDrain`,
+    /// `Special`, `Analog` and the `Function` modes fail with
+    /// [`SeesawError::UnsupportedPinMode`].
+    async fn set_pin_mode_bulk(
+        &mut self,
+        pins: u32,
+        mode: PinMode,
+    ) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+        let regs: &[&Reg] = match mode {
+            PinMode::Output => &[SET_OUTPUT],
+            PinMode::Input => &[SET_INPUT],
+            PinMode::Pullup => &[PULL_ENABLE, SET_HIGH],
+            PinMode::Pulldown => &[PULL_ENABLE, SET_LOW],
+            PinMode::InputPullup => &[SET_INPUT, PULL_ENABLE, SET_HIGH],
+            PinMode::InputPulldown => &[SET_INPUT, PULL_ENABLE, SET_LOW],
+            PinMode::OpenDrain
+            | PinMode::OutputOpenDrain
+            | PinMode::Special
+            | PinMode::Function1
+            | PinMode::Function2
+            | PinMode::Function3
+            | PinMode::Function4
+            | PinMode::Function5
+            | PinMode::Function6
+            | PinMode::Analog => return Err(SeesawError::UnsupportedPinMode(mode.into())),
+        };
+
+        for reg in regs {
+            self.driver()
+                .write_u32(addr, reg, pins)
+                .await
+                .map_err(SeesawError::I2c)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{
+    asynch::{AsyncDriver, AsyncDriverExt},
+    devices::SeesawDevice,
+    modules::keypad::{
+        KeyEvent, KeyEventDrain, KeyEventIter, KeyEventType, COUNT, DRAIN_ROUNDS, EVENT, FIFO,
+        FIFO_SIZE, INT_CLR, INT_SET,
+    },
+    SeesawError,
+};
+
+/// The async twin of [`KeypadModule`](crate::modules::keypad::KeypadModule)
+#[allow(async_fn_in_trait)]
+pub trait AsyncKeypadModule<D: AsyncDriver>: SeesawDevice<Driver = D> {
+    const NUM_COLS: u8;
+    const NUM_ROWS: u8;
+
+    fn num_cols(&self) -> u8 {
+        Self::NUM_COLS
+    }
+
+    fn num_rows(&self) -> u8 {
+        Self::NUM_ROWS
+    }
+
+    async fn disable_interrupt(&mut self) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+        self.driver()
+            .write_u8(addr, INT_CLR, 1)
+            .await
+            .map_err(SeesawError::I2c)
+    }
+
+    async fn enable_interrupt(&mut self) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+        self.driver()
+            .write_u8(addr, INT_SET, 1)
+            .await
+            .map_err(SeesawError::I2c)
+    }
+
+    /// Read the key events waiting in the FIFO, until it's empty
+    ///
+    /// The returned iterator holds as many events as the FIFO, so a single call
+    /// takes every event that was waiting. If more arrive while reading than
+    /// fit, the rest are left for the next call.
+    async fn read_key_events(&mut self) -> Result<KeyEventIter, SeesawError<D::Error>> {
+        let addr = self.addr();
+        let mut events = KeyEventIter::default();
+        let mut raw = [0; FIFO_SIZE];
+
+        loop {
+            let n = self
+                .driver()
+                .read_u8(addr, COUNT)
+                .await
+                .map_err(SeesawError::I2c)? as usize;
+            events.overflowed |= n >= FIFO_SIZE;
+            let n = n.min(FIFO_SIZE - events.len);
+            if n == 0 {
+                return Ok(events);
+            }
+            self.driver()
+                .register_read_into(addr, FIFO, &mut raw[..n])
+                .await
+                .map_err(SeesawError::I2c)?;
+            events.extend_raw(&raw[..n]);
+        }
+    }
+
+    /// Read key events until the FIFO is empty, passing each to `f`. See
+    /// [`KeypadModule::drain_key_events`](crate::modules::keypad::KeypadModule::drain_key_events)
+    /// for how many events a single call delivers.
+    async fn drain_key_events<F: FnMut(KeyEvent)>(
+        &mut self,
+        mut f: F,
+    ) -> Result<KeyEventDrain, SeesawError<D::Error>> {
+        let addr = self.addr();
+        let mut drain = KeyEventDrain::default();
+        let mut raw = [0; FIFO_SIZE];
+
+        for round in 0..=DRAIN_ROUNDS {
+            let n = self
+                .driver()
+                .read_u8(addr, COUNT)
+                .await
+                .map_err(SeesawError::I2c)? as usize;
+            drain.overflowed |= n >= FIFO_SIZE;
+            let n = n.min(FIFO_SIZE);
+            drain.more_pending = n > 0;
+            if n == 0 || round == DRAIN_ROUNDS {
+                break;
+            }
+            self.driver()
+                .register_read_into(addr, FIFO, &mut raw[..n])
+                .await
+                .map_err(SeesawError::I2c)?;
+            raw[..n].iter().for_each(|&e| f(e.into()));
+            drain.count += n;
+        }
+        Ok(drain)
+    }
+
+    async fn set_key_event_triggers(
+        &mut self,
+        x: u8,
+        y: u8,
+        types: &[KeyEventType],
+        enable: bool,
+    ) -> Result<(), SeesawError<D::Error>> {
+        assert!(x < Self::NUM_COLS, "x greater than cols");
+        assert!(y < Self::NUM_ROWS, "y greater than rows");
+        let addr = self.addr();
+        let key = (y << 3) + x;
+        let edges = types.iter().fold(if enable { 1 } else { 0 }, |acc, e| {
+            acc + (2_u8 << (*e as u8))
+        });
+        self.driver()
+            .register_write(addr, EVENT, &[key, edges])
+            .await
+            .map_err(SeesawError::I2c)
+    }
+}
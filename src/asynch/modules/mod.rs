@@ -0,0 +1,17 @@
+#[cfg(feature = "module_adc")]
+pub mod adc;
+#[cfg(feature = "module_eeprom")]
+pub mod eeprom;
+#[cfg(feature = "module_encoder")]
+pub mod encoder;
+#[cfg(feature = "module_gpio")]
+pub mod gpio;
+#[cfg(feature = "module_keypad")]
+pub mod keypad;
+#[cfg(feature = "module_neopixel")]
+pub mod neopixel;
+pub mod status;
+#[cfg(feature = "module_timer")]
+pub mod timer;
+#[cfg(feature = "module_touch")]
+pub mod touch;
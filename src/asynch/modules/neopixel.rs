@@ -0,0 +1,149 @@
+use crate::{
+    asynch::{AsyncDriver, AsyncDriverExt},
+    devices::SeesawDevice,
+    modules::neopixel::{
+        check_pixels, color_bytes_per_write, encode_colors, max_colors_per_write, ColorOrder,
+        NeopixelColor, NeopixelSpeed, SET_BUF, SET_LEN, SET_PIN, SET_SPEED, SHOW,
+    },
+    SeesawError,
+};
+
+/// The async twin of
+/// [`NeopixelModule`](crate::modules::neopixel::NeopixelModule)
+#[allow(async_fn_in_trait)]
+pub trait AsyncNeopixelModule<D: AsyncDriver>: SeesawDevice<Driver = D> {
+    /// The order in which the neopixels expect the color components of each
+    /// pixel
+    const COLOR_ORDER: ColorOrder = ColorOrder::Grb;
+    /// The size of each pixel in bytes
+    const C_SIZE: usize = Self::COLOR_ORDER.bytes_per_pixel();
+    /// The number of neopixels on or connected to the device, or the most it
+    /// can drive if the length of its strip is set at runtime
+    const N_LEDS: usize = 1;
+    /// The output pin of the neopixel signal
+    const PIN: u8;
+
+    /// The number of neopixels the device drives. Defaults to `N_LEDS`.
+    fn neopixel_len(&self) -> usize {
+        Self::N_LEDS
+    }
+
+    /// The order in which the neopixels expect the color components of each
+    /// pixel. Defaults to `COLOR_ORDER`.
+    fn neopixel_color_order(&self) -> ColorOrder {
+        Self::COLOR_ORDER
+    }
+
+    /// The output pin of the neopixel signal. Defaults to `PIN`.
+    fn neopixel_pin(&self) -> u8 {
+        Self::PIN
+    }
+
+    /// Set which pin the device sends the neopixel signal through and
+    /// set the length of its internal pixel buffer
+    async fn enable_neopixel(&mut self) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+        let pin = self.neopixel_pin();
+        let len = self.neopixel_len() * self.neopixel_color_order().bytes_per_pixel();
+
+        self.driver()
+            .write_u8(addr, SET_PIN, pin)
+            .await
+            .map_err(SeesawError::I2c)?;
+        self.driver().delay_us(10_000).await;
+        self.driver()
+            .write_u16(addr, SET_LEN, len as u16)
+            .await
+            .map_err(SeesawError::I2c)?;
+        self.driver().delay_us(10_000).await;
+        Ok(())
+    }
+
+    async fn set_neopixel_speed(
+        &mut self,
+        speed: NeopixelSpeed,
+    ) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+
+        self.driver()
+            .write_u8(
+                addr,
+                SET_SPEED,
+                match speed {
+                    NeopixelSpeed::Khz400 => 0,
+                    NeopixelSpeed::Khz800 => 1,
+                },
+            )
+            .await
+            .map_err(SeesawError::I2c)?;
+        self.driver().delay_us(10_000).await;
+        Ok(())
+    }
+
+    /// Set the color of the first (and, in the case of some devices, only)
+    /// neopixel
+    async fn set_neopixel_color<C: NeopixelColor>(
+        &mut self,
+        color: C,
+    ) -> Result<(), SeesawError<D::Error>> {
+        self.set_nth_neopixel_color(0, color).await
+    }
+
+    /// Set the color of the nth neopixel
+    async fn set_nth_neopixel_color<C: NeopixelColor>(
+        &mut self,
+        n: usize,
+        color: C,
+    ) -> Result<(), SeesawError<D::Error>> {
+        self.set_neopixel_colors_from(n, &[color]).await
+    }
+
+    /// Set the colors of the neopixels, starting from the first. `colors` may
+    /// be shorter than the strip, in which case the remaining neopixels are
+    /// left unchanged.
+    ///
+    /// Minimizes the number of transactions performed by chunking `colors`
+    /// into the largest (max 30 byte) buffers possible
+    async fn set_neopixel_colors<C: NeopixelColor>(
+        &mut self,
+        colors: &[C],
+    ) -> Result<(), SeesawError<D::Error>> {
+        self.set_neopixel_colors_from(0, colors).await
+    }
+
+    /// Set the colors of the neopixels, starting from the `first`, chunked like
+    /// [`set_neopixel_colors`](Self::set_neopixel_colors)
+    async fn set_neopixel_colors_from<C: NeopixelColor>(
+        &mut self,
+        first: usize,
+        colors: &[C],
+    ) -> Result<(), SeesawError<D::Error>> {
+        check_pixels(first, colors.len(), self.neopixel_len())?;
+        let addr = self.addr();
+        let order = self.neopixel_color_order();
+        let c_size = order.bytes_per_pixel();
+        let per_write = max_colors_per_write(c_size);
+        let mut buf = [0; 32];
+
+        for (i, chunk) in colors.chunks(per_write).enumerate() {
+            let offset = first * c_size + i * color_bytes_per_write(c_size);
+            let len = encode_colors(&mut buf, order, offset, chunk);
+            self.driver()
+                .register_write(addr, SET_BUF, &buf[..len])
+                .await
+                .map_err(SeesawError::I2c)?;
+        }
+        Ok(())
+    }
+
+    async fn sync_neopixel(&mut self) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+
+        self.driver()
+            .register_write(addr, SHOW, &[])
+            .await
+            .map_err(SeesawError::I2c)?;
+        self.driver().delay_us(125).await;
+        Ok(())
+    }
+}
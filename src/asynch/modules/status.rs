@@ -0,0 +1,75 @@
+use crate::{
+    asynch::{AsyncDriver, AsyncDriverExt},
+    devices::SeesawDevice,
+    modules::status::{
+        DeviceCapabilities, ProductDateCode, STATUS_HW_ID, STATUS_OPTIONS, STATUS_SWRST,
+        STATUS_TEMP, STATUS_VERSION,
+    },
+    SeesawError,
+};
+
+/// The async twin of [`StatusModule`](crate::modules::status::StatusModule)
+#[allow(async_fn_in_trait)]
+pub trait AsyncStatusModule<D: AsyncDriver>: SeesawDevice<Driver = D> {
+    /// Returns the available capabilities compiled into the seesaw firmware
+    async fn capabilities(&mut self) -> Result<DeviceCapabilities, SeesawError<D::Error>> {
+        let addr = self.addr();
+
+        self.driver()
+            .read_u32(addr, STATUS_OPTIONS)
+            .await
+            .map(|opts| opts.into())
+            .map_err(SeesawError::I2c)
+    }
+
+    async fn hardware_id(&mut self) -> Result<u8, SeesawError<D::Error>> {
+        let addr = self.addr();
+        self.driver()
+            .read_u8(addr, STATUS_HW_ID)
+            .await
+            .map_err(SeesawError::I2c)
+    }
+
+    /// Returns the version of the seesaw
+    async fn product_info(&mut self) -> Result<ProductDateCode, SeesawError<D::Error>> {
+        let addr = self.addr();
+
+        self.driver()
+            .read_u32(addr, STATUS_VERSION)
+            .await
+            .map(|version| version.into())
+            .map_err(SeesawError::I2c)
+    }
+
+    /// Perform a software reset. This resets all seesaw registers to
+    /// their default values.
+    async fn reset(&mut self) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+
+        self.driver()
+            .write_u8(addr, STATUS_SWRST, 0xFF)
+            .await
+            .map_err(SeesawError::I2c)?;
+        self.driver().delay_us(125_000).await;
+        Ok(())
+    }
+
+    async fn reset_and_verify_seesaw(&mut self) -> Result<(), SeesawError<D::Error>> {
+        let hw_id = Self::HARDWARE_ID;
+        self.reset().await?;
+        match self.hardware_id().await? {
+            id if id == hw_id.into() => Ok(()),
+            id => Err(SeesawError::InvalidHardwareId(id)),
+        }
+    }
+
+    async fn temp(&mut self) -> Result<f32, SeesawError<D::Error>> {
+        let addr = self.addr();
+
+        self.driver()
+            .read_u32(addr, STATUS_TEMP)
+            .await
+            .map(|buf| buf as f32 / (1u32 << 16) as f32)
+            .map_err(SeesawError::I2c)
+    }
+}
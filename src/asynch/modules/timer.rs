@@ -0,0 +1,52 @@
+use crate::{
+    asynch::{AsyncDriver, AsyncDriverExt},
+    devices::SeesawDevice,
+    modules::timer::{pwm_output, FREQ, PWM_VAL},
+    SeesawError,
+};
+
+/// The async twin of [`TimerModule`](crate::modules::timer::TimerModule)
+#[allow(async_fn_in_trait)]
+pub trait AsyncTimerModule<D: AsyncDriver>: SeesawDevice<Driver = D> {
+    /// Write a PWM value to a PWM-enabled pin
+    ///
+    /// On the SAMD09 breakout, the pin corresponds to the number on the
+    /// silkscreen. On the default seesaw firmware on the SAMD09 breakout,
+    /// pins 5, 6, and 7 are PWM enabled.
+    async fn analog_write(&mut self, pin: u8, value: u8) -> Result<(), SeesawError<D::Error>> {
+        let mapped_pin = pwm_output(Self::HARDWARE_ID, pin);
+
+        let addr = self.addr();
+        self.driver()
+            .write_u16(addr, PWM_VAL, u16::from_be_bytes([mapped_pin, value]))
+            .await
+            .map_err(SeesawError::I2c)
+    }
+
+    /// Write a 16-bit PWM value to a PWM-enabled pin
+    async fn analog_write_u16(&mut self, pin: u8, value: u16) -> Result<(), SeesawError<D::Error>> {
+        let [hi, lo] = value.to_be_bytes();
+        let mapped_pin = pwm_output(Self::HARDWARE_ID, pin);
+
+        let addr = self.addr();
+        self.driver()
+            .register_write(addr, PWM_VAL, &[mapped_pin, hi, lo])
+            .await
+            .map_err(SeesawError::I2c)
+    }
+
+    /// Set the frequency of a PWM-enabled pin in Hz
+    ///
+    /// Pins driven by the same timer share a frequency, so changing the
+    /// frequency of one pin may change it for others too.
+    async fn set_pwm_freq(&mut self, pin: u8, freq: u16) -> Result<(), SeesawError<D::Error>> {
+        let [hi, lo] = freq.to_be_bytes();
+        let mapped_pin = pwm_output(Self::HARDWARE_ID, pin);
+
+        let addr = self.addr();
+        self.driver()
+            .register_write(addr, FREQ, &[mapped_pin, hi, lo])
+            .await
+            .map_err(SeesawError::I2c)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{
+    asynch::AsyncDriver,
+    devices::SeesawDevice,
+    modules::touch::{channel_reg, touch_channel, READ_ATTEMPTS, READ_DELAY_US},
+    SeesawError,
+};
+
+/// The async twin of [`TouchModule`](crate::modules::touch::TouchModule)
+#[allow(async_fn_in_trait)]
+pub trait AsyncTouchModule<D: AsyncDriver>: SeesawDevice<Driver = D> {
+    /// Read the raw capacitive value of a touch-enabled pin. See
+    /// [`TouchModule::touch_read`](crate::modules::touch::TouchModule::touch_read)
+    /// for how pins map to touch channels.
+    async fn touch_read(&mut self, pin: u8) -> Result<u16, SeesawError<D::Error>> {
+        let addr = self.addr();
+        let reg = channel_reg(touch_channel(Self::HARDWARE_ID, pin));
+        let bus = self.driver();
+        let mut buf = [0u8; 2];
+        let mut result = Ok(());
+
+        for attempt in 0..READ_ATTEMPTS {
+            result = bus.write(addr, &reg).await;
+            if result.is_ok() {
+                bus.delay_us(READ_DELAY_US + attempt * 1_000).await;
+                result = bus.read(addr, &mut buf).await;
+            }
+            if result.is_ok() {
+                break;
+            }
+        }
+
+        result
+            .map(|_| u16::from_be_bytes(buf))
+            .map_err(SeesawError::I2c)
+    }
+}
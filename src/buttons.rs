@@ -0,0 +1,141 @@
+//! Key events, and edge detection for devices whose buttons are read as GPIO
+//! levels.
+//!
+//! Keypads like the NeoTrellis's report [`KeyEvent`]s themselves. Devices
+//! whose buttons are plain GPIO pins only report whether each is pressed, so a
+//! [`ButtonTracker`] compares successive reads to produce the same events:
+//!
+//! ```rs
+//! let mut tracker = ButtonTracker::<4>::new().with_debounce(3);
+//! loop {
+//!     tracker.update_values(&arcade.button_values()?, |event| handle(event));
+//! }
+//! ```
+//!
+//! Or from a bitmask of the buttons that are pressed:
+//!
+//! ```rs
+//! tracker.update(!neokeys.keys()? as u32, |event| handle(event));
+//! ```
+//!
+//! An encoder's button is tracked on its own:
+//!
+//! ```rs
+//! let mut tracker = ButtonTracker::<1>::new();
+//! tracker.update_values(&[encoder.button(0)?], |event| handle(event));
+//! ```
+
+/// Turns successive reads of `N` buttons, at most 32, into `Pressed` and
+/// `Released` [`KeyEvent`]s
+///
+/// Button `n` is reported at `x = n`, `y = 0`. Every button starts released.
+#[derive(Debug, Clone, Copy)]
+pub struct ButtonTracker<const N: usize> {
+    debounce: u8,
+    pressed: u32,
+    /// The number of reads in a row each button has differed from its state
+    changed_for: [u8; N],
+}
+
+impl<const N: usize> ButtonTracker<N> {
+    pub fn new() -> Self {
+        const { assert!(N <= 32, "more than 32 buttons") };
+        Self {
+            debounce: 1,
+            pressed: 0,
+            changed_for: [0; N],
+        }
+    }
+
+    /// Only report a change once it has been read `reads` times in a row
+    pub fn with_debounce(mut self, reads: u8) -> Self {
+        self.debounce = reads.max(1);
+        self
+    }
+
+    /// The buttons pressed after the last update, as a bitmask
+    pub fn pressed(&self) -> u32 {
+        self.pressed
+    }
+
+    /// Feed a read of the buttons, as a bitmask with a bit set for each one
+    /// pressed, passing the events of the buttons that changed to `f`. Bits
+    /// beyond `N` are ignored.
+    pub fn update<F: FnMut(KeyEvent)>(&mut self, pressed: u32, mut f: F) {
+        for (n, changed_for) in self.changed_for.iter_mut().enumerate() {
+            let bit = 1 << n;
+            if (pressed ^ self.pressed) & bit == 0 {
+                *changed_for = 0;
+                continue;
+            }
+            *changed_for += 1;
+            if *changed_for < self.debounce {
+                continue;
+            }
+            *changed_for = 0;
+            self.pressed ^= bit;
+            f(KeyEvent {
+                event: if pressed & bit != 0 {
+                    KeyEventType::Pressed
+                } else {
+                    KeyEventType::Released
+                },
+                x: n as u8,
+                y: 0,
+            });
+        }
+    }
+
+    /// Same as [`update`](Self::update), with a read of each button
+    pub fn update_values<F: FnMut(KeyEvent)>(&mut self, pressed: &[bool; N], f: F) {
+        let mask = pressed
+            .iter()
+            .enumerate()
+            .filter(|(_, &pressed)| pressed)
+            .fold(0, |mask, (n, _)| mask | (1 << n));
+        self.update(mask, f)
+    }
+}
+
+impl<const N: usize> Default for ButtonTracker<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u8)]
+pub enum KeyEventType {
+    /// steady-state key is down
+    IsPressed = 0,
+    /// steady-state key is up
+    IsReleased = 1,
+    /// one-shot as key is released
+    Released = 2,
+    /// one-shot as key is pressed
+    Pressed = 3,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct KeyEvent {
+    pub event: KeyEventType,
+    pub x: u8,
+    pub y: u8,
+}
+
+impl From<u8> for KeyEvent {
+    fn from(value: u8) -> Self {
+        let event = match value & 3 {
+            0 => KeyEventType::IsPressed,
+            1 => KeyEventType::IsReleased,
+            2 => KeyEventType::Released,
+            3 => KeyEventType::Pressed,
+            _ => unreachable!(),
+        };
+        let x = (value >> 2) & 7;
+        let y = (value >> 2) >> 3;
+        Self { event, x, y }
+    }
+}
@@ -0,0 +1,133 @@
+//! Brightness, gamma correction and current limiting for neopixels.
+//!
+//! A [`ColorCorrection`] turns the colors you draw into the colors that are
+//! written to the neopixels. It's applied to every frame flushed from a
+//! [`Framebuffer`](crate::framebuffer::Framebuffer), and can be applied by
+//! hand before writing colors with
+//! [`NeopixelModule`](crate::modules::neopixel::NeopixelModule):
+//!
+//! ```rs
+//! let correction = ColorCorrection::default()
+//!     .with_brightness(64)
+//!     .with_gamma(&GAMMA8)
+//!     .with_current_limit(500);
+//! let mut colors = [RGBA8::new(255, 255, 255, 0); 16];
+//! let milliamps = correction.apply(&mut colors);
+//! trellis.set_neopixel_colors(&colors)?;
+//! ```
+use rgb::RGBA8;
+
+/// A gamma 2.6 lookup table, matching the one in Adafruit's NeoPixel library
+pub const GAMMA8: [u8; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 3, 3, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 5, 6, 6, 6, 6, 7,
+    7, 7, 8, 8, 8, 9, 9, 9, 10, 10, 10, 11, 11, 11, 12, 12, 13, 13, 13, 14, 14, 15, 15, 16, 16, 17,
+    17, 18, 18, 19, 19, 20, 20, 21, 21, 22, 22, 23, 24, 24, 25, 25, 26, 27, 27, 28, 29, 29, 30, 31,
+    31, 32, 33, 34, 34, 35, 36, 37, 38, 38, 39, 40, 41, 42, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51,
+    52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 68, 69, 70, 71, 72, 73, 75, 76, 77,
+    78, 80, 81, 82, 84, 85, 86, 88, 89, 90, 92, 93, 94, 96, 97, 99, 100, 102, 103, 105, 106, 108,
+    109, 111, 112, 114, 115, 117, 119, 120, 122, 124, 125, 127, 129, 130, 132, 134, 136, 137, 139,
+    141, 143, 145, 146, 148, 150, 152, 154, 156, 158, 160, 162, 164, 166, 168, 170, 172, 174, 176,
+    178, 180, 182, 184, 186, 188, 191, 193, 195, 197, 199, 202, 204, 206, 209, 211, 213, 215, 218,
+    220, 223, 225, 227, 230, 232, 235, 237, 240, 242, 245, 247, 250, 252, 255,
+];
+
+/// How colors are corrected on their way to the neopixels
+///
+/// Each channel is gamma corrected, then scaled by the brightness. If the
+/// current the frame is estimated to draw exceeds the current limit, the
+/// whole frame is dimmed to fit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ColorCorrection {
+    /// The brightness every channel is scaled by, where 255 is full
+    /// brightness
+    pub brightness: u8,
+    /// The lookup table each channel is gamma corrected with
+    pub gamma: Option<&'static [u8; 256]>,
+    /// The most current, in milliamps, a frame may draw
+    pub current_limit_ma: Option<u32>,
+    /// The current, in milliamps, a single channel draws at full brightness
+    pub ma_per_channel: u32,
+}
+
+/// No correction, with WS2812 neopixels drawing 20mA per channel
+impl Default for ColorCorrection {
+    fn default() -> Self {
+        Self {
+            brightness: u8::MAX,
+            gamma: None,
+            current_limit_ma: None,
+            ma_per_channel: 20,
+        }
+    }
+}
+
+impl ColorCorrection {
+    pub fn with_brightness(mut self, brightness: u8) -> Self {
+        self.brightness = brightness;
+        self
+    }
+
+    pub fn with_gamma(mut self, gamma: &'static [u8; 256]) -> Self {
+        self.gamma = Some(gamma);
+        self
+    }
+
+    pub fn with_current_limit(mut self, milliamps: u32) -> Self {
+        self.current_limit_ma = Some(milliamps);
+        self
+    }
+
+    pub fn with_ma_per_channel(mut self, milliamps: u32) -> Self {
+        self.ma_per_channel = milliamps;
+        self
+    }
+
+    /// Gamma correct and scale a single color. This doesn't apply the current
+    /// limit, which depends on the whole frame.
+    pub fn correct(&self, color: RGBA8) -> RGBA8 {
+        map_channels(color, |c| {
+            let c = self.gamma.map_or(c, |gamma| gamma[c as usize]);
+            scale(c, self.brightness as u32, u8::MAX as u32)
+        })
+    }
+
+    /// Estimate the current, in milliamps, drawn by neopixels showing
+    /// `colors`, not counting what they draw while off
+    pub fn current_ma(&self, colors: &[RGBA8]) -> u32 {
+        let total: u32 = colors
+            .iter()
+            .map(|c| c.r as u32 + c.g as u32 + c.b as u32 + c.a as u32)
+            .sum();
+        total * self.ma_per_channel / u8::MAX as u32
+    }
+
+    /// Correct a frame of `colors` in place, dimming it to fit within the
+    /// current limit, and return its estimated current in milliamps
+    pub fn apply(&self, colors: &mut [RGBA8]) -> u32 {
+        colors.iter_mut().for_each(|c| *c = self.correct(*c));
+        let current = self.current_ma(colors);
+
+        match self.current_limit_ma {
+            Some(limit) if current > limit => {
+                colors
+                    .iter_mut()
+                    .for_each(|c| *c = map_channels(*c, |c| scale(c, limit, current)));
+                self.current_ma(colors)
+            }
+            _ => current,
+        }
+    }
+}
+
+/// Scale a channel by `num / den`, rounding down so a scaled frame never
+/// draws more than intended
+fn scale(c: u8, num: u32, den: u32) -> u8 {
+    (c as u32 * num / den) as u8
+}
+
+/// Apply `f` to each channel of `color`, including the white channel
+fn map_channels(color: RGBA8, f: impl Fn(u8) -> u8) -> RGBA8 {
+    RGBA8::new(f(color.r), f(color.g), f(color.b), f(color.a))
+}
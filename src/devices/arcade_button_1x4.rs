@@ -1,7 +1,16 @@
 use super::SeesawDeviceInit;
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+use crate::asynch::AsyncEepromModule;
+#[cfg(feature = "async")]
+use crate::asynch::{
+    AsyncButtonInterrupts, AsyncDriver, AsyncGpioModule, AsyncSeesawDeviceInit, AsyncStatusModule,
+    AsyncTimerModule,
+};
+#[cfg(feature = "module_eeprom")]
+use crate::modules::eeprom::EepromModule;
 use crate::{
     modules::{
-        gpio::{GpioModule, PinMode},
+        gpio::{pin_mask, Button, GpioModule, PinMode},
         status::StatusModule,
         timer::TimerModule,
         HardwareId,
@@ -32,8 +41,22 @@ seesaw_device! {
   default_addr: 0x3A
 }
 
+/// The pins of the four buttons, SW1 through SW4
+const BUTTON_PINS: [u8; 4] = [18, 19, 20, 2];
+const BUTTON_MASK: u32 = pin_mask(&BUTTON_PINS);
+const LED_PINS: [u8; 4] = [12, 13, 0, 1];
+
 impl<D: Driver> GpioModule<D> for ArcadeButton1x4<D> {}
 impl<D: Driver> TimerModule<D> for ArcadeButton1x4<D> {}
+#[cfg(feature = "module_eeprom")]
+impl<D: Driver> EepromModule<D> for ArcadeButton1x4<D> {}
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncGpioModule<D> for ArcadeButton1x4<D> {}
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncTimerModule<D> for ArcadeButton1x4<D> {}
+
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+impl<D: AsyncDriver> AsyncEepromModule<D> for ArcadeButton1x4<D> {}
 
 impl<D: Driver> SeesawDeviceInit<D> for ArcadeButton1x4<D> {
     fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
@@ -43,28 +66,64 @@ impl<D: Driver> SeesawDeviceInit<D> for ArcadeButton1x4<D> {
     }
 }
 
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncSeesawDeviceInit<D> for ArcadeButton1x4<D> {
+    async fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
+        self.reset_and_verify_seesaw().await?;
+        self.set_pin_mode_bulk(BUTTON_MASK, PinMode::InputPullup)
+            .await?;
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncButtonInterrupts<D> for ArcadeButton1x4<D> {
+    const BUTTON_MASK: u32 = BUTTON_MASK;
+}
+
 impl<D: Driver> ArcadeButton1x4<D> {
+    /// Whether each button is pressed, read in a single transaction
     pub fn button_values(&mut self) -> Result<[bool; 4], SeesawError<D::Error>> {
-        let mut values = [false; 4];
-        for (i, pin) in [18, 19, 20, 2].iter().enumerate() {
-            values[i] = self.digital_read(*pin)?;
-        }
-        Ok(values)
+        self.read_buttons(&BUTTON_PINS.map(Button::active_low))
+    }
+
+    /// Enable pin-change interrupts on the four buttons, so the IRQ pin is
+    /// asserted whenever a button is pressed or released
+    pub fn enable_button_interrupts(&mut self) -> Result<(), SeesawError<D::Error>> {
+        self.enable_pin_interrupt_bulk(BUTTON_MASK)
     }
 
     /// Set the pin mode of the 4 buttons to input pullup:
     pub fn enable_buttons(&mut self) -> Result<(), SeesawError<D::Error>> {
-        self.set_pin_mode(18, PinMode::InputPullup)?;
-        self.set_pin_mode(19, PinMode::InputPullup)?;
-        self.set_pin_mode(20, PinMode::InputPullup)?;
-        self.set_pin_mode(2, PinMode::InputPullup)?;
-        Ok(())
+        self.set_pin_mode_bulk(BUTTON_MASK, PinMode::InputPullup)
+    }
+
+    /// Same as [`init`](SeesawDeviceInit::init), but also enables the
+    /// interrupts on the buttons
+    pub fn init_with_interrupts(self) -> Result<Self, SeesawError<D::Error>> {
+        let mut device = self.init()?;
+        device.enable_button_interrupts()?;
+        // Clear any changes latched while the pins were being configured
+        device.read_pin_interrupt_flags()?;
+        Ok(device)
     }
 
     pub fn set_led_duty_cycles(&mut self, pwms: &[u8; 4]) -> Result<(), SeesawError<D::Error>> {
-        [12u8, 13, 0, 1]
+        LED_PINS
             .iter()
             .enumerate()
             .try_for_each(|(i, &pin)| self.analog_write(pin, pwms[i]))
     }
+
+    /// Same as [`set_led_duty_cycles`](Self::set_led_duty_cycles), but with
+    /// 16-bit resolution for smoother dimming
+    pub fn set_led_duty_cycles_u16(
+        &mut self,
+        pwms: &[u16; 4],
+    ) -> Result<(), SeesawError<D::Error>> {
+        LED_PINS
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, &pin)| self.analog_write_u16(pin, pwms[i]))
+    }
 }
@@ -1,4 +1,10 @@
 use super::SeesawDeviceInit;
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+use crate::asynch::AsyncEepromModule;
+#[cfg(feature = "async")]
+use crate::asynch::{AsyncDriver, AsyncSeesawDeviceInit, AsyncStatusModule};
+#[cfg(feature = "module_eeprom")]
+use crate::modules::eeprom::EepromModule;
 use crate::{
     modules::{status::StatusModule, HardwareId},
     seesaw_device, Driver, SeesawError,
@@ -12,8 +18,21 @@ seesaw_device! {
   default_addr: 0x49
 }
 
+#[cfg(feature = "module_eeprom")]
+impl<D: Driver> EepromModule<D> for GenericDevice<D> {}
+
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+impl<D: AsyncDriver> AsyncEepromModule<D> for GenericDevice<D> {}
+
 impl<D: Driver> SeesawDeviceInit<D> for GenericDevice<D> {
     fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
         self.reset().map(|_| self)
     }
 }
+
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncSeesawDeviceInit<D> for GenericDevice<D> {
+    async fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
+        self.reset().await.map(|_| self)
+    }
+}
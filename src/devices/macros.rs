@@ -6,13 +6,32 @@ macro_rules! seesaw_device {
         hardware_id: $hardware_id:expr,
         product_id: $product_id:expr,
         default_addr: $default_addr:expr
+    ) => {
+        seesaw_device! {
+            $(#[$attr])*
+            name: $name,
+            hardware_id: $hardware_id,
+            product_id: $product_id,
+            default_addr: $default_addr,
+            config: ()
+        }
+    };
+    // Devices that are configured at runtime hold a `config`, which starts out
+    // as its `Default`
+    (
+        $(#[$attr:meta])*
+        name: $name:ident,
+        hardware_id: $hardware_id:expr,
+        product_id: $product_id:expr,
+        default_addr: $default_addr:expr,
+        config: $config:ty
     ) => {
         #[doc=core::concat!("[Adafruit Product Page](https://www.adafruit.com/product/", core::stringify!($product_id),")")]
         #[doc=core::concat!("")]
         $(#[$attr])*
         #[derive(Debug)]
         #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-        pub struct $name<D>(u8, D);
+        pub struct $name<D>(u8, D, $config);
 
         impl $name<()> {
             pub const fn default_addr() -> u8 {
@@ -26,7 +45,7 @@ macro_rules! seesaw_device {
             }
         }
 
-        impl<D: $crate::Driver> $crate::devices::SeesawDevice for $name<D> {
+        impl<D> $crate::devices::SeesawDevice for $name<D> {
             type Driver = D;
             const DEFAULT_ADDR: u8 = $default_addr;
             const HARDWARE_ID: $crate::modules::HardwareId = $hardware_id;
@@ -36,16 +55,24 @@ macro_rules! seesaw_device {
                 self.0
             }
 
+            fn set_addr(&mut self, addr: u8) {
+                self.0 = addr;
+            }
+
             fn driver(&mut self) -> &mut D {
                 &mut self.1
             }
 
             fn new(addr: u8, driver: D) -> Self {
-                Self(addr, driver)
+                Self(addr, driver, <$config>::default())
             }
 
             fn new_with_default_addr(driver: D) -> Self {
-                Self(Self::DEFAULT_ADDR, driver)
+                Self(Self::DEFAULT_ADDR, driver, <$config>::default())
+            }
+
+            fn release(self) -> D {
+                self.1
             }
         }
     };
@@ -2,6 +2,10 @@
 mod arcade_button_1x4;
 mod generic_device;
 pub mod macros;
+#[cfg(all(feature = "device_neotrellis", feature = "module_neopixel"))]
+mod multi_trellis;
+#[cfg(feature = "device_neodriver")]
+mod neodriver;
 #[cfg(feature = "device_neokey_1x4")]
 mod neokey_1x4;
 #[cfg(feature = "device_neorotary4")]
@@ -12,6 +16,8 @@ mod neoslider;
 mod neotrellis;
 #[cfg(feature = "device_rotary_encoder")]
 mod rotary_encoder;
+#[cfg(feature = "device_soil_sensor")]
+mod soil_sensor;
 use crate::{
     modules::{status::StatusModule, HardwareId},
     Driver, SeesawError,
@@ -19,6 +25,10 @@ use crate::{
 #[cfg(feature = "device_arcade_button_1x4")]
 pub use arcade_button_1x4::*;
 pub use generic_device::*;
+#[cfg(all(feature = "device_neotrellis", feature = "module_neopixel"))]
+pub use multi_trellis::*;
+#[cfg(feature = "device_neodriver")]
+pub use neodriver::*;
 #[cfg(feature = "device_neokey_1x4")]
 pub use neokey_1x4::*;
 #[cfg(feature = "device_neorotary4")]
@@ -29,9 +39,11 @@ pub use neoslider::*;
 pub use neotrellis::*;
 #[cfg(feature = "device_rotary_encoder")]
 pub use rotary_encoder::*;
+#[cfg(feature = "device_soil_sensor")]
+pub use soil_sensor::*;
 
 pub trait SeesawDevice {
-    type Driver: crate::Driver;
+    type Driver;
 
     const DEFAULT_ADDR: u8;
     const HARDWARE_ID: HardwareId;
@@ -39,11 +51,18 @@ pub trait SeesawDevice {
 
     fn addr(&self) -> u8;
 
+    /// Rebind the device to a different address, e.g. after it has been
+    /// moved with `change_addr`
+    fn set_addr(&mut self, addr: u8);
+
     fn driver(&mut self) -> &mut Self::Driver;
 
     fn new(addr: u8, driver: Self::Driver) -> Self;
 
     fn new_with_default_addr(driver: Self::Driver) -> Self;
+
+    /// Consume the device, returning its driver
+    fn release(self) -> Self::Driver;
 }
 
 /// All devices implement the status module
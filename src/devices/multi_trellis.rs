@@ -0,0 +1,269 @@
+use super::NeoTrellis;
+use crate::{
+    correction::ColorCorrection,
+    framebuffer::Framebuffer,
+    modules::{
+        keypad::{KeyEvent, KeyEventIter, KeyEventType, KeypadModule},
+        neopixel::NeopixelColor,
+    },
+    Driver, SeesawError,
+};
+use rgb::RGBA8;
+
+/// The number of keys along each side of a NeoTrellis
+const TILE_SIZE: u8 = 4;
+
+/// Several [`NeoTrellis`] boards tiled into one grid, like the Arduino
+/// library's `Adafruit_MultiTrellis`
+///
+/// The boards are laid out `ROWS` high and `COLS` wide, with `boards[0][0]`
+/// in the top left. Keys and pixels are addressed by their global `(x, y)`
+/// position on the grid, which is translated to the board and its local key.
+///
+/// ```rs
+/// let board = |addr| NeoTrellis::new(addr, SeesawDriver::new(delay, RefCellDevice::new(&bus)));
+/// let mut grid = MultiTrellis::new([
+///     [board(0x2E).init()?, board(0x2F).init()?],
+///     [board(0x30).init()?, board(0x31).init()?],
+/// ]);
+/// for y in 0..grid.height() {
+///     for x in 0..grid.width() {
+///         grid.set_key_event_triggers(x, y, &[KeyEventType::Pressed], true)?;
+///     }
+/// }
+/// for event in grid.read_key_events()? {
+///     grid.set_pixel(event.x, event.y, RGB8::new(0, 0, 255));
+/// }
+/// grid.flush()?;
+/// ```
+#[derive(Debug)]
+pub struct MultiTrellis<D, const COLS: usize, const ROWS: usize> {
+    tiles: [[Framebuffer<D, NeoTrellis<D>, 16>; COLS]; ROWS],
+}
+
+impl<D: Driver, const COLS: usize, const ROWS: usize> MultiTrellis<D, COLS, ROWS> {
+    /// Tile `boards`, which should already be initialized
+    pub fn new(boards: [[NeoTrellis<D>; COLS]; ROWS]) -> Self {
+        const {
+            assert!(
+                COLS * (TILE_SIZE as usize) <= u8::MAX as usize
+                    && ROWS * (TILE_SIZE as usize) <= u8::MAX as usize,
+                "too many boards to address every key with a u8"
+            )
+        };
+        Self {
+            tiles: boards.map(|row| row.map(Framebuffer::new)),
+        }
+    }
+
+    /// Give back the boards
+    pub fn release(self) -> [[NeoTrellis<D>; COLS]; ROWS] {
+        self.tiles.map(|row| row.map(Framebuffer::release))
+    }
+
+    /// The number of keys across the grid
+    pub const fn width(&self) -> u8 {
+        COLS as u8 * TILE_SIZE
+    }
+
+    /// The number of keys down the grid
+    pub const fn height(&self) -> u8 {
+        ROWS as u8 * TILE_SIZE
+    }
+
+    /// The board in column `col` and row `row` of the layout
+    pub fn board(&mut self, col: usize, row: usize) -> &mut NeoTrellis<D> {
+        self.tiles[row][col].device()
+    }
+
+    /// The board the key at `(x, y)` is on, and the key's local position on
+    /// that board
+    pub fn locate(&mut self, x: u8, y: u8) -> (&mut NeoTrellis<D>, u8, u8) {
+        let (tile, x, y) = self.tile(x, y);
+        (tile.device(), x, y)
+    }
+
+    /// Enable the keypad interrupt of every board, so its IRQ pin is asserted
+    /// while it has key events waiting to be read
+    pub fn enable_interrupts(&mut self) -> Result<(), SeesawError<D::Error>> {
+        self.tiles
+            .iter_mut()
+            .flatten()
+            .try_for_each(|tile| tile.device().enable_interrupt())
+    }
+
+    /// Disable the keypad interrupt of every board
+    pub fn disable_interrupts(&mut self) -> Result<(), SeesawError<D::Error>> {
+        self.tiles
+            .iter_mut()
+            .flatten()
+            .try_for_each(|tile| tile.device().disable_interrupt())
+    }
+
+    /// Read the key events of every board, merged into one stream with global
+    /// coordinates
+    ///
+    /// A board that fails to be read doesn't stop the others from being read,
+    /// since reading a board's events removes them from its FIFO. The first
+    /// error is returned in a [`PartialKeyEvents`] alongside the events of the
+    /// boards that were read.
+    pub fn read_key_events(
+        &mut self,
+    ) -> Result<MultiKeyEventIter<COLS, ROWS>, PartialKeyEvents<D::Error, COLS, ROWS>> {
+        let mut boards = [[KeyEventIter::default(); COLS]; ROWS];
+        let mut error = None;
+        for (tiles, events) in self.tiles.iter_mut().zip(boards.iter_mut()) {
+            for (tile, events) in tiles.iter_mut().zip(events.iter_mut()) {
+                match tile.device().read_key_events() {
+                    Ok(read) => *events = read,
+                    Err(e) => {
+                        error.get_or_insert(e);
+                    }
+                }
+            }
+        }
+        let events = MultiKeyEventIter {
+            boards,
+            col: 0,
+            row: 0,
+        };
+        match error {
+            None => Ok(events),
+            Some(error) => Err(PartialKeyEvents { events, error }),
+        }
+    }
+
+    pub fn set_key_event_triggers(
+        &mut self,
+        x: u8,
+        y: u8,
+        types: &[KeyEventType],
+        enable: bool,
+    ) -> Result<(), SeesawError<D::Error>> {
+        let (trellis, x, y) = self.locate(x, y);
+        trellis.set_key_event_triggers(x, y, types, enable)
+    }
+
+    /// The color of the pixel at `(x, y)`
+    pub fn get_pixel(&self, x: u8, y: u8) -> RGBA8 {
+        assert!(x < self.width(), "x greater than cols");
+        assert!(y < self.height(), "y greater than rows");
+        self.tiles[(y / TILE_SIZE) as usize][(x / TILE_SIZE) as usize].pixels()[local_index(x, y)]
+    }
+
+    /// Set the color of the pixel at `(x, y)`
+    pub fn set_pixel<C: NeopixelColor>(&mut self, x: u8, y: u8, color: C) {
+        let (tile, _, _) = self.tile(x, y);
+        tile.pixels_mut()[local_index(x, y)] = color.to_rgbw();
+    }
+
+    /// Set the color of every pixel on every board
+    pub fn fill<C: NeopixelColor>(&mut self, color: C) {
+        self.tiles
+            .iter_mut()
+            .flatten()
+            .for_each(|tile| tile.fill(color));
+    }
+
+    /// Set the color correction applied to every board. The current limit
+    /// applies to each board separately.
+    pub fn set_correction(&mut self, correction: ColorCorrection) {
+        self.tiles
+            .iter_mut()
+            .flatten()
+            .for_each(|tile| tile.set_correction(correction));
+    }
+
+    /// The estimated current, in milliamps, drawn by the last flushed frame
+    /// across every board
+    pub fn current_ma(&self) -> u32 {
+        self.tiles
+            .iter()
+            .flatten()
+            .map(Framebuffer::current_ma)
+            .sum()
+    }
+
+    /// Make the next flush send every pixel of every board
+    pub fn invalidate(&mut self) {
+        self.tiles
+            .iter_mut()
+            .flatten()
+            .for_each(Framebuffer::invalidate);
+    }
+
+    /// Write the pixels that changed since the last flush to each board and
+    /// show them, returning the number of bytes written to the bus
+    pub fn flush(&mut self) -> Result<usize, SeesawError<D::Error>> {
+        self.tiles
+            .iter_mut()
+            .flatten()
+            .try_fold(0, |sent, tile| Ok(sent + tile.flush()?))
+    }
+
+    /// The tile the key at `(x, y)` is on, and the key's local position
+    fn tile(&mut self, x: u8, y: u8) -> (&mut Framebuffer<D, NeoTrellis<D>, 16>, u8, u8) {
+        assert!(x < self.width(), "x greater than cols");
+        assert!(y < self.height(), "y greater than rows");
+        (
+            &mut self.tiles[(y / TILE_SIZE) as usize][(x / TILE_SIZE) as usize],
+            x % TILE_SIZE,
+            y % TILE_SIZE,
+        )
+    }
+}
+
+/// The index of the key and neopixel at global `(x, y)` on its board
+fn local_index(x: u8, y: u8) -> usize {
+    ((y % TILE_SIZE) * TILE_SIZE + x % TILE_SIZE).into()
+}
+
+/// The events read by [`MultiTrellis::read_key_events`] from the boards that
+/// could be read, and the error from the first board that couldn't
+///
+/// Converts into its `error`, so `?` can be used where the events of the
+/// other boards don't matter.
+#[derive(Debug)]
+pub struct PartialKeyEvents<E, const COLS: usize, const ROWS: usize> {
+    pub events: MultiKeyEventIter<COLS, ROWS>,
+    pub error: SeesawError<E>,
+}
+
+impl<E, const COLS: usize, const ROWS: usize> From<PartialKeyEvents<E, COLS, ROWS>>
+    for SeesawError<E>
+{
+    fn from(value: PartialKeyEvents<E, COLS, ROWS>) -> Self {
+        value.error
+    }
+}
+
+/// The key events of every board of a [`MultiTrellis`], with global
+/// coordinates
+#[derive(Debug, Clone, Copy)]
+pub struct MultiKeyEventIter<const COLS: usize, const ROWS: usize> {
+    boards: [[KeyEventIter; COLS]; ROWS],
+    col: usize,
+    row: usize,
+}
+
+impl<const COLS: usize, const ROWS: usize> Iterator for MultiKeyEventIter<COLS, ROWS> {
+    type Item = KeyEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while COLS > 0 && self.row < ROWS {
+            if let Some(event) = self.boards[self.row][self.col].next() {
+                return Some(KeyEvent {
+                    x: event.x + self.col as u8 * TILE_SIZE,
+                    y: event.y + self.row as u8 * TILE_SIZE,
+                    ..event
+                });
+            }
+            self.col += 1;
+            if self.col == COLS {
+                self.col = 0;
+                self.row += 1;
+            }
+        }
+        None
+    }
+}
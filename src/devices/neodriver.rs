@@ -0,0 +1,191 @@
+use super::SeesawDeviceInit;
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+use crate::asynch::AsyncEepromModule;
+#[cfg(feature = "async")]
+use crate::asynch::{AsyncDriver, AsyncNeopixelModule, AsyncSeesawDeviceInit, AsyncStatusModule};
+#[cfg(feature = "module_eeprom")]
+use crate::modules::eeprom::EepromModule;
+use crate::{
+    modules::{
+        neopixel::{ColorOrder, NeopixelModule},
+        status::StatusModule,
+        HardwareId,
+    },
+    seesaw_device, Driver, SeesawError,
+};
+
+seesaw_device! {
+  /// NeoDriver
+  ///
+  /// Unlike the other NeoPixel devices, the length of the strip connected to a
+  /// NeoDriver isn't known until runtime, so it's configured with
+  /// [`with_len`](Self::with_len), [`with_color_order`](Self::with_color_order)
+  /// and [`with_pin`](Self::with_pin) before calling
+  /// [`init`](SeesawDeviceInit::init), or changed afterwards with their `set_`
+  /// counterparts:
+  ///
+  /// ```rs
+  /// let mut strip = NeoDriver::new_with_default_addr(seesaw)
+  ///     .with_len(config.num_pixels)
+  ///     .with_color_order(ColorOrder::Rgb)
+  ///     .init()?;
+  /// strip.set_neopixel_colors(&colors)?;
+  /// strip.sync_neopixel()?;
+  /// ```
+  name: NeoDriver,
+  hardware_id: HardwareId::ATTINY817,
+  product_id: 5766,
+  default_addr: 0x60,
+  config: Strip
+}
+
+/// The strip a [`NeoDriver`] is configured to drive
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct Strip {
+    len: usize,
+    order: ColorOrder,
+    pin: u8,
+}
+
+impl Default for Strip {
+    fn default() -> Self {
+        Self {
+            len: 0,
+            order: ColorOrder::default(),
+            pin: NeoDriver::DEFAULT_PIN,
+        }
+    }
+}
+
+impl Strip {
+    /// Fails with [`SeesawError::NeopixelBufferOverflow`] if the strip doesn't
+    /// fit in the firmware's pixel buffer
+    fn check_buf_len<E>(&self) -> Result<(), SeesawError<E>> {
+        let len = self.len * self.order.bytes_per_pixel();
+        match len <= NeoDriver::MAX_BUF_LEN {
+            true => Ok(()),
+            false => Err(SeesawError::NeopixelBufferOverflow {
+                len,
+                max: NeoDriver::MAX_BUF_LEN,
+            }),
+        }
+    }
+}
+
+impl<D: Driver> NeopixelModule<D> for NeoDriver<D> {
+    const N_LEDS: usize = NeoDriver::MAX_BUF_LEN / ColorOrder::Grb.bytes_per_pixel();
+    const PIN: u8 = NeoDriver::DEFAULT_PIN;
+
+    fn neopixel_len(&self) -> usize {
+        self.2.len
+    }
+
+    fn neopixel_color_order(&self) -> ColorOrder {
+        self.2.order
+    }
+
+    fn neopixel_pin(&self) -> u8 {
+        self.2.pin
+    }
+}
+
+#[cfg(feature = "module_eeprom")]
+impl<D: Driver> EepromModule<D> for NeoDriver<D> {}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncNeopixelModule<D> for NeoDriver<D> {
+    const N_LEDS: usize = NeoDriver::MAX_BUF_LEN / ColorOrder::Grb.bytes_per_pixel();
+    const PIN: u8 = NeoDriver::DEFAULT_PIN;
+
+    fn neopixel_len(&self) -> usize {
+        self.2.len
+    }
+
+    fn neopixel_color_order(&self) -> ColorOrder {
+        self.2.order
+    }
+
+    fn neopixel_pin(&self) -> u8 {
+        self.2.pin
+    }
+}
+
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+impl<D: AsyncDriver> AsyncEepromModule<D> for NeoDriver<D> {}
+
+impl<D: Driver> SeesawDeviceInit<D> for NeoDriver<D> {
+    fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
+        self.2.check_buf_len()?;
+        self.reset_and_verify_seesaw()?;
+        self.enable_neopixel()?;
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncSeesawDeviceInit<D> for NeoDriver<D> {
+    async fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
+        self.2.check_buf_len()?;
+        self.reset_and_verify_seesaw().await?;
+        self.enable_neopixel().await?;
+        Ok(self)
+    }
+}
+
+impl NeoDriver<()> {
+    /// The pin the NeoPixel output is broken out on
+    pub const DEFAULT_PIN: u8 = 15;
+    /// The size of the firmware's pixel buffer in bytes
+    pub const MAX_BUF_LEN: usize = 512;
+}
+
+impl<D> NeoDriver<D> {
+    /// Set the number of pixels on the strip
+    pub fn with_len(mut self, len: usize) -> Self {
+        self.2.len = len;
+        self
+    }
+
+    /// Set the order the strip expects color components in
+    pub fn with_color_order(mut self, order: ColorOrder) -> Self {
+        self.2.order = order;
+        self
+    }
+
+    /// Set the pin the NeoPixel signal is output on
+    pub fn with_pin(mut self, pin: u8) -> Self {
+        self.2.pin = pin;
+        self
+    }
+}
+
+impl<D: Driver> NeoDriver<D> {
+    /// Change the order the strip expects color components in, resizing the
+    /// pixel buffer if the number of bytes per pixel changed
+    pub fn set_color_order(&mut self, order: ColorOrder) -> Result<(), SeesawError<D::Error>> {
+        self.reconfigure(Strip { order, ..self.2 })
+    }
+
+    /// Change the number of pixels on the strip
+    ///
+    /// Fails with [`SeesawError::NeopixelBufferOverflow`], leaving the
+    /// previous length in place, if the strip doesn't fit in the firmware's
+    /// pixel buffer.
+    pub fn set_len(&mut self, len: usize) -> Result<(), SeesawError<D::Error>> {
+        self.reconfigure(Strip { len, ..self.2 })
+    }
+
+    /// Change the pin the NeoPixel signal is output on
+    pub fn set_pin(&mut self, pin: u8) -> Result<(), SeesawError<D::Error>> {
+        self.reconfigure(Strip { pin, ..self.2 })
+    }
+
+    /// Drive `strip` instead, restoring the previous configuration if the
+    /// device can't be switched over to it
+    fn reconfigure(&mut self, strip: Strip) -> Result<(), SeesawError<D::Error>> {
+        strip.check_buf_len()?;
+        let prev = core::mem::replace(&mut self.2, strip);
+        self.enable_neopixel().inspect_err(|_| self.2 = prev)
+    }
+}
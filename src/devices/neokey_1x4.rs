@@ -1,9 +1,19 @@
 use super::SeesawDeviceInit;
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+use crate::asynch::AsyncEepromModule;
+#[cfg(all(feature = "async", feature = "module_neopixel"))]
+use crate::asynch::AsyncNeopixelModule;
+#[cfg(feature = "async")]
+use crate::asynch::{
+    AsyncButtonInterrupts, AsyncDriver, AsyncGpioModule, AsyncSeesawDeviceInit, AsyncStatusModule,
+};
+#[cfg(feature = "module_eeprom")]
+use crate::modules::eeprom::EepromModule;
 #[cfg(feature = "module_neopixel")]
 use crate::modules::neopixel::NeopixelModule;
 use crate::{
     modules::{
-        gpio::{GpioModule, PinMode},
+        gpio::{pin_mask, Button, GpioModule, PinMode},
         status::StatusModule,
         HardwareId,
     },
@@ -20,15 +30,31 @@ seesaw_device! {
 
 pub type NeoKey1x4Color = rgb::Grb<u8>;
 
+/// The pins of the four keys
+const KEY_PINS: [u8; 4] = [4, 5, 6, 7];
+const KEY_MASK: u32 = pin_mask(&KEY_PINS);
+
 impl<D: Driver> GpioModule<D> for NeoKey1x4<D> {}
 #[cfg(feature = "module_neopixel")]
 impl<D: Driver> NeopixelModule<D> for NeoKey1x4<D> {
-    type Color = NeoKey1x4Color;
+    const N_LEDS: usize = 4;
+    const PIN: u8 = 3;
+}
 
+#[cfg(feature = "module_eeprom")]
+impl<D: Driver> EepromModule<D> for NeoKey1x4<D> {}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncGpioModule<D> for NeoKey1x4<D> {}
+#[cfg(all(feature = "async", feature = "module_neopixel"))]
+impl<D: AsyncDriver> AsyncNeopixelModule<D> for NeoKey1x4<D> {
     const N_LEDS: usize = 4;
     const PIN: u8 = 3;
 }
 
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+impl<D: AsyncDriver> AsyncEepromModule<D> for NeoKey1x4<D> {}
+
 impl<D: Driver> SeesawDeviceInit<D> for NeoKey1x4<D> {
     fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
         self.reset_and_verify_seesaw()?;
@@ -39,15 +65,52 @@ impl<D: Driver> SeesawDeviceInit<D> for NeoKey1x4<D> {
     }
 }
 
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncSeesawDeviceInit<D> for NeoKey1x4<D> {
+    async fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
+        self.reset_and_verify_seesaw().await?;
+        self.set_pin_mode_bulk(KEY_MASK, PinMode::InputPullup)
+            .await?;
+        #[cfg(feature = "module_neopixel")]
+        self.enable_neopixel().await?;
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncButtonInterrupts<D> for NeoKey1x4<D> {
+    const BUTTON_MASK: u32 = KEY_MASK;
+}
+
 impl<D: Driver> NeoKey1x4<D> {
+    /// Enable pin-change interrupts on the four keys, so the IRQ pin is
+    /// asserted whenever a key is pressed or released
+    pub fn enable_button_interrupts(&mut self) -> Result<(), SeesawError<D::Error>> {
+        self.enable_pin_interrupt_bulk(KEY_MASK)
+    }
+
     pub fn enable_button_pins(&mut self) -> Result<(), SeesawError<D::Error>> {
-        self.set_pin_mode_bulk(
-            (1 << 4) | (1 << 5) | (1 << 6) | (1 << 7),
-            PinMode::InputPullup,
-        )
+        self.set_pin_mode_bulk(KEY_MASK, PinMode::InputPullup)
+    }
+
+    /// Same as [`init`](SeesawDeviceInit::init), but also enables the
+    /// interrupts on the keys
+    pub fn init_with_interrupts(self) -> Result<Self, SeesawError<D::Error>> {
+        let mut device = self.init()?;
+        device.enable_button_interrupts()?;
+        // Clear any changes latched while the pins were being configured
+        device.read_pin_interrupt_flags()?;
+        Ok(device)
     }
 
+    /// The levels of the four keys, as a bitmask. The keys are pulled up, so
+    /// a key's bit is clear while it's pressed.
     pub fn keys(&mut self) -> Result<u8, SeesawError<D::Error>> {
         self.digital_read_bulk().map(|r| ((r >> 4) & 0xF) as u8)
     }
+
+    /// Whether each key is pressed, read in a single transaction
+    pub fn key_values(&mut self) -> Result<[bool; 4], SeesawError<D::Error>> {
+        self.read_buttons(&KEY_PINS.map(Button::active_low))
+    }
 }
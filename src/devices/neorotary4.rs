@@ -1,4 +1,14 @@
 use super::SeesawDeviceInit;
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+use crate::asynch::AsyncEepromModule;
+#[cfg(all(feature = "async", feature = "module_neopixel"))]
+use crate::asynch::AsyncNeopixelModule;
+#[cfg(feature = "async")]
+use crate::asynch::{
+    AsyncDriver, AsyncEncoderModule, AsyncGpioModule, AsyncSeesawDeviceInit, AsyncStatusModule,
+};
+#[cfg(feature = "module_eeprom")]
+use crate::modules::eeprom::EepromModule;
 #[cfg(feature = "module_neopixel")]
 use crate::modules::neopixel::NeopixelModule;
 use crate::{
@@ -26,21 +36,45 @@ impl<D: Driver> EncoderModule<D, 4> for NeoRotary4<D> {
 }
 #[cfg(feature = "module_neopixel")]
 impl<D: Driver> NeopixelModule<D> for NeoRotary4<D> {
-    type Color = NeoRotary4Color;
+    const N_LEDS: usize = 4;
+    const PIN: u8 = 18;
+}
 
+#[cfg(feature = "module_eeprom")]
+impl<D: Driver> EepromModule<D> for NeoRotary4<D> {}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncGpioModule<D> for NeoRotary4<D> {}
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncEncoderModule<D, 4> for NeoRotary4<D> {
+    const ENCODER_BTN_PINS: [u8; 4] = [12, 14, 17, 9];
+}
+#[cfg(all(feature = "async", feature = "module_neopixel"))]
+impl<D: AsyncDriver> AsyncNeopixelModule<D> for NeoRotary4<D> {
     const N_LEDS: usize = 4;
     const PIN: u8 = 18;
 }
 
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+impl<D: AsyncDriver> AsyncEepromModule<D> for NeoRotary4<D> {}
+
 impl<D: Driver> SeesawDeviceInit<D> for NeoRotary4<D> {
     fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
         self.reset_and_verify_seesaw()?;
-        self.enable_button(0)?;
-        self.enable_button(1)?;
-        self.enable_button(2)?;
-        self.enable_button(3)?;
+        self.enable_buttons()?;
         #[cfg(feature = "module_neopixel")]
         self.enable_neopixel()?;
         Ok(self)
     }
 }
+
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncSeesawDeviceInit<D> for NeoRotary4<D> {
+    async fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
+        self.reset_and_verify_seesaw().await?;
+        self.enable_buttons().await?;
+        #[cfg(feature = "module_neopixel")]
+        self.enable_neopixel().await?;
+        Ok(self)
+    }
+}
@@ -1,4 +1,14 @@
 use super::SeesawDeviceInit;
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+use crate::asynch::AsyncEepromModule;
+#[cfg(all(feature = "async", feature = "module_neopixel"))]
+use crate::asynch::AsyncNeopixelModule;
+#[cfg(feature = "async")]
+use crate::asynch::{
+    AsyncAdcModule, AsyncDriver, AsyncGpioModule, AsyncSeesawDeviceInit, AsyncStatusModule,
+};
+#[cfg(feature = "module_eeprom")]
+use crate::modules::eeprom::EepromModule;
 #[cfg(feature = "module_neopixel")]
 use crate::modules::neopixel::NeopixelModule;
 use crate::{
@@ -20,12 +30,26 @@ impl<D: Driver> AdcModule<D> for NeoSlider<D> {}
 impl<D: Driver> GpioModule<D> for NeoSlider<D> {}
 #[cfg(feature = "module_neopixel")]
 impl<D: Driver> NeopixelModule<D> for NeoSlider<D> {
-    type Color = NeoSliderColor;
+    const N_LEDS: usize = 4;
+    const PIN: u8 = 14;
+}
+
+#[cfg(feature = "module_eeprom")]
+impl<D: Driver> EepromModule<D> for NeoSlider<D> {}
 
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncAdcModule<D> for NeoSlider<D> {}
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncGpioModule<D> for NeoSlider<D> {}
+#[cfg(all(feature = "async", feature = "module_neopixel"))]
+impl<D: AsyncDriver> AsyncNeopixelModule<D> for NeoSlider<D> {
     const N_LEDS: usize = 4;
     const PIN: u8 = 14;
 }
 
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+impl<D: AsyncDriver> AsyncEepromModule<D> for NeoSlider<D> {}
+
 impl<D: Driver> SeesawDeviceInit<D> for NeoSlider<D> {
     fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
         self.reset_and_verify_seesaw()?;
@@ -35,6 +59,16 @@ impl<D: Driver> SeesawDeviceInit<D> for NeoSlider<D> {
     }
 }
 
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncSeesawDeviceInit<D> for NeoSlider<D> {
+    async fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
+        self.reset_and_verify_seesaw().await?;
+        #[cfg(feature = "module_neopixel")]
+        self.enable_neopixel().await?;
+        Ok(self)
+    }
+}
+
 impl<D: Driver> NeoSlider<D> {
     pub fn slider_value(&mut self) -> Result<u16, SeesawError<D::Error>> {
         self.analog_read(18)
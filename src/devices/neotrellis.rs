@@ -1,10 +1,25 @@
 use super::SeesawDeviceInit;
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+use crate::asynch::AsyncEepromModule;
+#[cfg(all(feature = "async", feature = "module_neopixel"))]
+use crate::asynch::AsyncNeopixelModule;
+#[cfg(feature = "async")]
+use crate::asynch::{AsyncDriver, AsyncKeypadModule, AsyncSeesawDeviceInit, AsyncStatusModule};
+#[cfg(feature = "module_eeprom")]
+use crate::modules::eeprom::EepromModule;
 #[cfg(feature = "module_neopixel")]
-use crate::modules::neopixel::NeopixelModule;
+use crate::modules::neopixel::{NeopixelColor, NeopixelModule};
 use crate::{
     modules::{keypad::KeypadModule, status::StatusModule, HardwareId},
     seesaw_device, Driver, SeesawError,
 };
+#[cfg(feature = "embedded_graphics")]
+use embedded_graphics_core::{
+    draw_target::DrawTarget,
+    geometry::{OriginDimensions, Size},
+    pixelcolor::{Rgb888, RgbColor},
+    Pixel,
+};
 
 seesaw_device! {
     name: NeoTrellis,
@@ -22,12 +37,28 @@ impl<D: Driver> KeypadModule<D> for NeoTrellis<D> {
 
 #[cfg(feature = "module_neopixel")]
 impl<D: Driver> NeopixelModule<D> for NeoTrellis<D> {
-    type Color = NeoTrellisColor;
+    const N_LEDS: usize = 16;
+    const PIN: u8 = 3;
+}
+
+#[cfg(feature = "module_eeprom")]
+impl<D: Driver> EepromModule<D> for NeoTrellis<D> {}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncKeypadModule<D> for NeoTrellis<D> {
+    const NUM_COLS: u8 = 4;
+    const NUM_ROWS: u8 = 4;
+}
 
+#[cfg(all(feature = "async", feature = "module_neopixel"))]
+impl<D: AsyncDriver> AsyncNeopixelModule<D> for NeoTrellis<D> {
     const N_LEDS: usize = 16;
     const PIN: u8 = 3;
 }
 
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+impl<D: AsyncDriver> AsyncEepromModule<D> for NeoTrellis<D> {}
+
 impl<D: Driver> SeesawDeviceInit<D> for NeoTrellis<D> {
     fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
         self.reset_and_verify_seesaw()?;
@@ -37,17 +68,106 @@ impl<D: Driver> SeesawDeviceInit<D> for NeoTrellis<D> {
     }
 }
 
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncSeesawDeviceInit<D> for NeoTrellis<D> {
+    async fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
+        self.reset_and_verify_seesaw().await?;
+        #[cfg(feature = "module_neopixel")]
+        self.enable_neopixel().await?;
+        Ok(self)
+    }
+}
+
 impl<D: Driver> NeoTrellis<D> {
     #[cfg(feature = "module_neopixel")]
-    pub fn set_xy_neopixel_color(
+    pub fn set_xy_neopixel_color<C: NeopixelColor>(
         &mut self,
         x: u8,
         y: u8,
-        color: NeoTrellisColor,
-    ) -> Result<(), SeesawError<D::Error>>
+        color: C,
+    ) -> Result<(), SeesawError<D::Error>> {
+        self.set_nth_neopixel_color(Self::xy_index(x, y), color)
+    }
+
+    /// The index of the key and neopixel in column `x` of row `y`
+    #[cfg(feature = "module_neopixel")]
+    fn xy_index(x: u8, y: u8) -> usize {
+        (y * Self::NUM_COLS + x).into()
+    }
+}
+
+/// An [`embedded-graphics`](https://docs.rs/embedded-graphics) draw target
+/// for the neopixels of a [`NeoTrellis`]
+///
+/// The seesaw's pixel buffer can't be read back, so the display keeps a copy
+/// of the 4x4 grid. Each draw updates the copy and writes the whole grid to
+/// the neopixels with one `set_neopixel_colors` and `sync_neopixel`, leaving
+/// the pixels it didn't touch as they were.
+///
+/// ```rs
+/// let mut display = NeoTrellisDisplay::new(trellis);
+/// Rectangle::new(Point::new(1, 1), Size::new(2, 2))
+///     .into_styled(PrimitiveStyle::with_fill(Rgb888::RED))
+///     .draw(&mut display)?;
+/// ```
+#[cfg(feature = "embedded_graphics")]
+#[derive(Debug)]
+pub struct NeoTrellisDisplay<D> {
+    pixels: [NeoTrellisColor; 16],
+    trellis: NeoTrellis<D>,
+}
+
+#[cfg(feature = "embedded_graphics")]
+impl<D: Driver> NeoTrellisDisplay<D> {
+    /// Draw to the neopixels of `trellis`, which start off
+    pub fn new(trellis: NeoTrellis<D>) -> Self {
+        Self {
+            pixels: [NeoTrellisColor::default(); 16],
+            trellis,
+        }
+    }
+
+    /// Give back the NeoTrellis
+    pub fn release(self) -> NeoTrellis<D> {
+        self.trellis
+    }
+
+    /// The NeoTrellis being drawn to
+    pub fn trellis(&mut self) -> &mut NeoTrellis<D> {
+        &mut self.trellis
+    }
+}
+
+#[cfg(feature = "embedded_graphics")]
+impl<D: Driver> OriginDimensions for NeoTrellisDisplay<D> {
+    fn size(&self) -> Size {
+        Size::new(
+            NeoTrellis::<D>::NUM_COLS.into(),
+            NeoTrellis::<D>::NUM_ROWS.into(),
+        )
+    }
+}
+
+#[cfg(feature = "embedded_graphics")]
+impl<D: Driver> DrawTarget for NeoTrellisDisplay<D> {
+    type Color = Rgb888;
+    type Error = SeesawError<D::Error>;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
     where
-        [(); 2 + Self::C_SIZE]: Sized,
+        I: IntoIterator<Item = Pixel<Self::Color>>,
     {
-        self.set_nth_neopixel_color((y * Self::NUM_COLS + x).into(), color)
+        let size = self.size();
+        pixels
+            .into_iter()
+            .filter(|Pixel(p, _)| {
+                (0..size.width as i32).contains(&p.x) && (0..size.height as i32).contains(&p.y)
+            })
+            .for_each(|Pixel(p, color)| {
+                self.pixels[NeoTrellis::<D>::xy_index(p.x as u8, p.y as u8)] =
+                    NeoTrellisColor::new_grb(color.g(), color.r(), color.b());
+            });
+        self.trellis.set_neopixel_colors(&self.pixels)?;
+        self.trellis.sync_neopixel()
     }
 }
@@ -1,4 +1,14 @@
 use super::SeesawDeviceInit;
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+use crate::asynch::AsyncEepromModule;
+#[cfg(all(feature = "async", feature = "module_neopixel"))]
+use crate::asynch::AsyncNeopixelModule;
+#[cfg(feature = "async")]
+use crate::asynch::{
+    AsyncDriver, AsyncEncoderModule, AsyncGpioModule, AsyncSeesawDeviceInit, AsyncStatusModule,
+};
+#[cfg(feature = "module_eeprom")]
+use crate::modules::eeprom::EepromModule;
 #[cfg(feature = "module_neopixel")]
 use crate::modules::neopixel::NeopixelModule;
 use crate::{
@@ -22,12 +32,29 @@ impl<D: Driver> EncoderModule<D, 1> for RotaryEncoder<D> {
 
 #[cfg(feature = "module_neopixel")]
 impl<D: Driver> NeopixelModule<D> for RotaryEncoder<D> {
-    type Color = RotaryEncoderColor;
+    const N_LEDS: usize = 1;
+    const PIN: u8 = 6;
+}
+
+#[cfg(feature = "module_eeprom")]
+impl<D: Driver> EepromModule<D> for RotaryEncoder<D> {}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncGpioModule<D> for RotaryEncoder<D> {}
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncEncoderModule<D, 1> for RotaryEncoder<D> {
+    const ENCODER_BTN_PINS: [u8; 1] = [24];
+}
 
+#[cfg(all(feature = "async", feature = "module_neopixel"))]
+impl<D: AsyncDriver> AsyncNeopixelModule<D> for RotaryEncoder<D> {
     const N_LEDS: usize = 1;
     const PIN: u8 = 6;
 }
 
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+impl<D: AsyncDriver> AsyncEepromModule<D> for RotaryEncoder<D> {}
+
 impl<D: Driver> SeesawDeviceInit<D> for RotaryEncoder<D> {
     fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
         self.reset_and_verify_seesaw()?;
@@ -37,3 +64,14 @@ impl<D: Driver> SeesawDeviceInit<D> for RotaryEncoder<D> {
         Ok(self)
     }
 }
+
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncSeesawDeviceInit<D> for RotaryEncoder<D> {
+    async fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
+        self.reset_and_verify_seesaw().await?;
+        self.enable_button(0).await?;
+        #[cfg(feature = "module_neopixel")]
+        self.enable_neopixel().await?;
+        Ok(self)
+    }
+}
@@ -0,0 +1,105 @@
+use super::SeesawDeviceInit;
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+use crate::asynch::AsyncEepromModule;
+#[cfg(feature = "async")]
+use crate::asynch::{AsyncDriver, AsyncSeesawDeviceInit, AsyncStatusModule, AsyncTouchModule};
+#[cfg(feature = "module_eeprom")]
+use crate::modules::eeprom::EepromModule;
+use crate::{
+    modules::{status::StatusModule, touch::TouchModule, HardwareId},
+    seesaw_device, Driver, SeesawError,
+};
+
+seesaw_device! {
+  name: SoilSensor,
+  hardware_id: HardwareId::SAMD09,
+  product_id: 4026,
+  default_addr: 0x36
+}
+
+/// The pin the moisture probe is read from, which reads touch channel 0 like
+/// the Arduino library's `touchRead(0)`
+const PROBE_PIN: u8 = 0;
+
+impl<D: Driver> TouchModule<D> for SoilSensor<D> {}
+
+#[cfg(feature = "module_eeprom")]
+impl<D: Driver> EepromModule<D> for SoilSensor<D> {}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncTouchModule<D> for SoilSensor<D> {}
+
+#[cfg(all(feature = "async", feature = "module_eeprom"))]
+impl<D: AsyncDriver> AsyncEepromModule<D> for SoilSensor<D> {}
+
+impl<D: Driver> SeesawDeviceInit<D> for SoilSensor<D> {
+    fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
+        self.reset_and_verify_seesaw().map(|_| self)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDriver> AsyncSeesawDeviceInit<D> for SoilSensor<D> {
+    async fn init(mut self) -> Result<Self, SeesawError<D::Error>> {
+        self.reset_and_verify_seesaw().await.map(|_| self)
+    }
+}
+
+impl<D: Driver> SoilSensor<D> {
+    /// The raw capacitance measured by the probe, which rises with moisture.
+    /// Expect values from around 200 in dry air to around 2000 in water.
+    pub fn moisture(&mut self) -> Result<u16, SeesawError<D::Error>> {
+        self.touch_read(PROBE_PIN)
+    }
+
+    /// The moisture as a percentage between the `calibration`'s dry and wet
+    /// reference points
+    pub fn moisture_percent(
+        &mut self,
+        calibration: &MoistureCalibration,
+    ) -> Result<f32, SeesawError<D::Error>> {
+        self.moisture().map(|raw| calibration.percent(raw))
+    }
+
+    /// The temperature of the sensor's microcontroller in degrees Celsius,
+    /// accurate to within a couple of degrees
+    pub fn temperature(&mut self) -> Result<f32, SeesawError<D::Error>> {
+        self.temp()
+    }
+}
+
+/// Maps raw readings from a [`SoilSensor`] to a 0-100% moisture range
+///
+/// Sensors and soils vary, so for meaningful percentages take a reading with
+/// the probe in dry soil and another in saturated soil, and use those as the
+/// reference points.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MoistureCalibration {
+    /// The raw reading corresponding to 0%
+    pub dry: u16,
+    /// The raw reading corresponding to 100%
+    pub wet: u16,
+}
+
+impl MoistureCalibration {
+    pub const fn new(dry: u16, wet: u16) -> Self {
+        Self { dry, wet }
+    }
+
+    /// Map a raw reading to a percentage, clamped to 0-100%
+    pub fn percent(&self, raw: u16) -> f32 {
+        if self.dry == self.wet {
+            return 0.0;
+        }
+        let fraction = (raw as f32 - self.dry as f32) / (self.wet as f32 - self.dry as f32);
+        fraction.clamp(0.0, 1.0) * 100.0
+    }
+}
+
+/// Spans the full range of readings the probe produces, from dry air to water
+impl Default for MoistureCalibration {
+    fn default() -> Self {
+        Self::new(200, 2000)
+    }
+}
@@ -0,0 +1,181 @@
+//! [`embedded-hal` digital pins](embedded_hal::digital) for individual seesaw
+//! GPIOs.
+//!
+//! A [`GpioPin`] borrows its device from a [`RefCell`], so a single device can
+//! be split into as many pins as you need, each of which can be handed to a
+//! driver that expects an `InputPin` or `OutputPin`:
+//!
+//! ```rs
+//! let device = RefCell::new(NeoKey1x4::new_with_default_addr(seesaw).init()?);
+//! let mut cs = GpioPin::output(&device, 2, PinState::High)?;
+//! let mut key = GpioPin::input_pullup(&device, 4)?;
+//! cs.set_low()?;
+//! let pressed = key.is_low()?;
+//! ```
+//!
+//! The pin's mode is tracked in its type: only [`Input`] pins implement
+//! `InputPin`, and only [`Output`] pins implement `OutputPin` and
+//! `StatefulOutputPin`.
+use crate::{
+    modules::gpio::{GpioModule, PinMode, PinOutput},
+    Driver, SeesawError,
+};
+use core::{cell::RefCell, fmt::Debug, marker::PhantomData};
+use embedded_hal::digital::{
+    ErrorKind, ErrorType, InputPin, OutputPin, PinState, StatefulOutputPin,
+};
+
+impl<E: Debug> embedded_hal::digital::Error for SeesawError<E> {
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Other
+    }
+}
+
+/// Type-state of a pin configured as an input
+#[derive(Debug)]
+pub struct Input;
+
+/// Type-state of a pin configured as a push-pull output
+#[derive(Debug)]
+pub struct Output;
+
+/// A single GPIO pin of a seesaw device
+#[derive(Debug)]
+pub struct GpioPin<'a, D, T, MODE> {
+    device: &'a RefCell<T>,
+    pin: u8,
+    _mode: PhantomData<(D, MODE)>,
+}
+
+impl<'a, D: Driver, T: GpioModule<D>> GpioPin<'a, D, T, Input> {
+    /// Configure `pin` as a floating input, disabling any pull resistor left
+    /// enabled by an earlier mode
+    pub fn input(device: &'a RefCell<T>, pin: u8) -> Result<Self, SeesawError<D::Error>> {
+        let input = Self::configure(device, pin, PinMode::Input)?;
+        device.borrow_mut().disable_pull(pin)?;
+        Ok(input)
+    }
+
+    /// Configure `pin` as an input with its pull-down resistor enabled
+    pub fn input_pulldown(device: &'a RefCell<T>, pin: u8) -> Result<Self, SeesawError<D::Error>> {
+        Self::configure(device, pin, PinMode::InputPulldown)
+    }
+
+    /// Configure `pin` as an input with its pull-up resistor enabled
+    pub fn input_pullup(device: &'a RefCell<T>, pin: u8) -> Result<Self, SeesawError<D::Error>> {
+        Self::configure(device, pin, PinMode::InputPullup)
+    }
+}
+
+impl<'a, D: Driver, T: GpioModule<D>> GpioPin<'a, D, T, Output> {
+    /// Configure `pin` as an output, initially driven to `state`
+    pub fn output(
+        device: &'a RefCell<T>,
+        pin: u8,
+        state: PinState,
+    ) -> Result<Self, SeesawError<D::Error>> {
+        device.borrow_mut().digital_write(pin, state.into())?;
+        Self::configure(device, pin, PinMode::Output)
+    }
+}
+
+impl<'a, D: Driver, T: GpioModule<D>, MODE> GpioPin<'a, D, T, MODE> {
+    fn configure<M>(
+        device: &'a RefCell<T>,
+        pin: u8,
+        mode: PinMode,
+    ) -> Result<GpioPin<'a, D, T, M>, SeesawError<D::Error>> {
+        device.borrow_mut().set_pin_mode(pin, mode)?;
+        Ok(GpioPin {
+            device,
+            pin,
+            _mode: PhantomData,
+        })
+    }
+
+    /// Reconfigure the pin as a floating input
+    pub fn into_input(self) -> Result<GpioPin<'a, D, T, Input>, SeesawError<D::Error>> {
+        GpioPin::input(self.device, self.pin)
+    }
+
+    /// Reconfigure the pin as an input with its pull-down resistor enabled
+    pub fn into_input_pulldown(self) -> Result<GpioPin<'a, D, T, Input>, SeesawError<D::Error>> {
+        GpioPin::input_pulldown(self.device, self.pin)
+    }
+
+    /// Reconfigure the pin as an input with its pull-up resistor enabled
+    pub fn into_input_pullup(self) -> Result<GpioPin<'a, D, T, Input>, SeesawError<D::Error>> {
+        GpioPin::input_pullup(self.device, self.pin)
+    }
+
+    /// Reconfigure the pin as an output, initially driven to `state`
+    pub fn into_output(
+        self,
+        state: PinState,
+    ) -> Result<GpioPin<'a, D, T, Output>, SeesawError<D::Error>> {
+        GpioPin::output(self.device, self.pin, state)
+    }
+
+    /// The pin number
+    pub fn pin(&self) -> u8 {
+        self.pin
+    }
+
+    fn is_high(&self) -> Result<bool, SeesawError<D::Error>> {
+        self.device
+            .borrow_mut()
+            .digital_read_bulk()
+            .map(|pins| (pins >> self.pin) & 1 == 1)
+    }
+
+    fn write(&self, output: PinOutput) -> Result<(), SeesawError<D::Error>> {
+        self.device.borrow_mut().digital_write(self.pin, output)
+    }
+}
+
+impl From<PinState> for PinOutput {
+    fn from(value: PinState) -> Self {
+        match value {
+            PinState::High => PinOutput::High,
+            PinState::Low => PinOutput::Low,
+        }
+    }
+}
+
+impl<D: Driver, T: GpioModule<D>, MODE> ErrorType for GpioPin<'_, D, T, MODE> {
+    type Error = SeesawError<D::Error>;
+}
+
+impl<D: Driver, T: GpioModule<D>> InputPin for GpioPin<'_, D, T, Input> {
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        GpioPin::is_high(self)
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        GpioPin::is_high(self).map(|high| !high)
+    }
+}
+
+impl<D: Driver, T: GpioModule<D>> OutputPin for GpioPin<'_, D, T, Output> {
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.write(PinOutput::High)
+    }
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.write(PinOutput::Low)
+    }
+}
+
+impl<D: Driver, T: GpioModule<D>> StatefulOutputPin for GpioPin<'_, D, T, Output> {
+    fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+        GpioPin::is_high(self)
+    }
+
+    fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+        GpioPin::is_high(self).map(|high| !high)
+    }
+
+    fn toggle(&mut self) -> Result<(), Self::Error> {
+        self.write(PinOutput::Toggle)
+    }
+}
@@ -0,0 +1,235 @@
+//! Discover and identify seesaw devices on an I2C bus.
+//!
+//! ```rs
+//! for report in scan(&mut driver, SEESAW_ADDR_RANGE) {
+//!     let report = report.expect("Bus error while scanning");
+//!     rprintln!("{:#04x}: {:?}", report.addr, report.device);
+//! }
+//! ```
+#[cfg(feature = "device_arcade_button_1x4")]
+use crate::devices::ArcadeButton1x4;
+#[cfg(feature = "device_neodriver")]
+use crate::devices::NeoDriver;
+#[cfg(feature = "device_neokey_1x4")]
+use crate::devices::NeoKey1x4;
+#[cfg(feature = "device_neorotary4")]
+use crate::devices::NeoRotary4;
+#[cfg(feature = "device_neoslider")]
+use crate::devices::NeoSlider;
+#[cfg(feature = "device_neotrellis")]
+use crate::devices::NeoTrellis;
+#[cfg(feature = "device_rotary_encoder")]
+use crate::devices::RotaryEncoder;
+#[cfg(feature = "device_soil_sensor")]
+use crate::devices::SoilSensor;
+use crate::{
+    devices::{GenericDevice, SeesawDevice},
+    modules::{
+        status::{DeviceCapabilities, ProductDateCode, StatusModule},
+        HardwareId,
+    },
+    Driver, SeesawError,
+};
+use core::ops::RangeInclusive;
+use embedded_hal::i2c::{Error, ErrorKind, SevenBitAddress};
+
+/// Every non-reserved 7-bit I2C address
+pub const SEESAW_ADDR_RANGE: RangeInclusive<SevenBitAddress> = 0x08..=0x77;
+
+/// A predefined device in [`devices`](crate::devices) that a seesaw was
+/// identified as. Only the devices enabled by feature flags are identified.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum KnownDevice {
+    #[cfg(feature = "device_arcade_button_1x4")]
+    ArcadeButton1x4,
+    #[cfg(feature = "device_neodriver")]
+    NeoDriver,
+    #[cfg(feature = "device_neokey_1x4")]
+    NeoKey1x4,
+    #[cfg(feature = "device_neorotary4")]
+    NeoRotary4,
+    #[cfg(feature = "device_neoslider")]
+    NeoSlider,
+    #[cfg(feature = "device_neotrellis")]
+    NeoTrellis,
+    #[cfg(feature = "device_rotary_encoder")]
+    RotaryEncoder,
+    #[cfg(feature = "device_soil_sensor")]
+    SoilSensor,
+}
+
+/// Every predefined device enabled by feature flags
+const KNOWN_DEVICES: &[KnownDevice] = &[
+    #[cfg(feature = "device_arcade_button_1x4")]
+    KnownDevice::ArcadeButton1x4,
+    #[cfg(feature = "device_neodriver")]
+    KnownDevice::NeoDriver,
+    #[cfg(feature = "device_neokey_1x4")]
+    KnownDevice::NeoKey1x4,
+    #[cfg(feature = "device_neorotary4")]
+    KnownDevice::NeoRotary4,
+    #[cfg(feature = "device_neoslider")]
+    KnownDevice::NeoSlider,
+    #[cfg(feature = "device_neotrellis")]
+    KnownDevice::NeoTrellis,
+    #[cfg(feature = "device_rotary_encoder")]
+    KnownDevice::RotaryEncoder,
+    #[cfg(feature = "device_soil_sensor")]
+    KnownDevice::SoilSensor,
+];
+
+impl KnownDevice {
+    /// Identify a device from its hardware ID, product info and capabilities.
+    ///
+    /// Some firmware reports the wrong product ID, as listed under the README's
+    /// Known Issues. The NeoTrellis firmware reports a product ID of 0, which
+    /// doesn't identify a device by itself. Of the predefined devices, only the
+    /// NeoTrellis is a SAMD09 with a keypad module, so a device reporting 0 is
+    /// assumed to be a NeoTrellis if it's also both of those, and is otherwise
+    /// unknown. A custom SAMD09 keypad that reports 0 will be misidentified.
+    /// The NeoDriver firmware reports a product ID of 5742 rather than 5766.
+    #[cfg_attr(not(feature = "device_neotrellis"), allow(unused_variables))]
+    pub fn identify(
+        hardware_id: HardwareId,
+        product: &ProductDateCode,
+        capabilities: &DeviceCapabilities,
+    ) -> Option<Self> {
+        let device = match product.id {
+            #[cfg(feature = "device_neotrellis")]
+            0 if hardware_id == HardwareId::SAMD09 && capabilities.keypad => Self::NeoTrellis,
+            #[cfg(feature = "device_neodriver")]
+            5742 => Self::NeoDriver,
+            id => KNOWN_DEVICES
+                .iter()
+                .copied()
+                .find(|device| device.product_id() == id)?,
+        };
+
+        (device.hardware_id() == hardware_id).then_some(device)
+    }
+
+    pub const fn default_addr(self) -> SevenBitAddress {
+        match self {
+            #[cfg(feature = "device_arcade_button_1x4")]
+            Self::ArcadeButton1x4 => ArcadeButton1x4::default_addr(),
+            #[cfg(feature = "device_neodriver")]
+            Self::NeoDriver => NeoDriver::default_addr(),
+            #[cfg(feature = "device_neokey_1x4")]
+            Self::NeoKey1x4 => NeoKey1x4::default_addr(),
+            #[cfg(feature = "device_neorotary4")]
+            Self::NeoRotary4 => NeoRotary4::default_addr(),
+            #[cfg(feature = "device_neoslider")]
+            Self::NeoSlider => NeoSlider::default_addr(),
+            #[cfg(feature = "device_neotrellis")]
+            Self::NeoTrellis => NeoTrellis::default_addr(),
+            #[cfg(feature = "device_rotary_encoder")]
+            Self::RotaryEncoder => RotaryEncoder::default_addr(),
+            #[cfg(feature = "device_soil_sensor")]
+            Self::SoilSensor => SoilSensor::default_addr(),
+        }
+    }
+
+    pub const fn hardware_id(self) -> HardwareId {
+        match self {
+            #[cfg(feature = "device_arcade_button_1x4")]
+            Self::ArcadeButton1x4 => ArcadeButton1x4::hardware_id(),
+            #[cfg(feature = "device_neodriver")]
+            Self::NeoDriver => NeoDriver::hardware_id(),
+            #[cfg(feature = "device_neokey_1x4")]
+            Self::NeoKey1x4 => NeoKey1x4::hardware_id(),
+            #[cfg(feature = "device_neorotary4")]
+            Self::NeoRotary4 => NeoRotary4::hardware_id(),
+            #[cfg(feature = "device_neoslider")]
+            Self::NeoSlider => NeoSlider::hardware_id(),
+            #[cfg(feature = "device_neotrellis")]
+            Self::NeoTrellis => NeoTrellis::hardware_id(),
+            #[cfg(feature = "device_rotary_encoder")]
+            Self::RotaryEncoder => RotaryEncoder::hardware_id(),
+            #[cfg(feature = "device_soil_sensor")]
+            Self::SoilSensor => SoilSensor::hardware_id(),
+        }
+    }
+
+    pub const fn product_id(self) -> u16 {
+        match self {
+            #[cfg(feature = "device_arcade_button_1x4")]
+            Self::ArcadeButton1x4 => ArcadeButton1x4::product_id(),
+            #[cfg(feature = "device_neodriver")]
+            Self::NeoDriver => NeoDriver::product_id(),
+            #[cfg(feature = "device_neokey_1x4")]
+            Self::NeoKey1x4 => NeoKey1x4::product_id(),
+            #[cfg(feature = "device_neorotary4")]
+            Self::NeoRotary4 => NeoRotary4::product_id(),
+            #[cfg(feature = "device_neoslider")]
+            Self::NeoSlider => NeoSlider::product_id(),
+            #[cfg(feature = "device_neotrellis")]
+            Self::NeoTrellis => NeoTrellis::product_id(),
+            #[cfg(feature = "device_rotary_encoder")]
+            Self::RotaryEncoder => RotaryEncoder::product_id(),
+            #[cfg(feature = "device_soil_sensor")]
+            Self::SoilSensor => SoilSensor::product_id(),
+        }
+    }
+}
+
+/// What was found at an address that responded like a seesaw
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DeviceReport {
+    pub addr: SevenBitAddress,
+    pub hardware_id: HardwareId,
+    pub product: ProductDateCode,
+    pub capabilities: DeviceCapabilities,
+    /// The predefined device type to construct, if the device is one
+    pub device: Option<KnownDevice>,
+}
+
+/// Probe each address in `addrs` for a seesaw device.
+///
+/// Addresses that don't acknowledge, or that respond with an unknown hardware
+/// ID, are skipped. Any other bus error is yielded and scanning continues at
+/// the next address.
+pub fn scan<D: Driver>(driver: &mut D, addrs: RangeInclusive<SevenBitAddress>) -> Scanner<'_, D> {
+    Scanner { addrs, driver }
+}
+
+/// An iterator over the seesaw devices on a bus, created by [`scan`]
+pub struct Scanner<'a, D> {
+    addrs: RangeInclusive<SevenBitAddress>,
+    driver: &'a mut D,
+}
+
+impl<D: Driver> Scanner<'_, D> {
+    fn probe(&mut self, addr: SevenBitAddress) -> Result<DeviceReport, SeesawError<D::Error>> {
+        let mut device = GenericDevice::new(addr, &mut *self.driver);
+        let hardware_id = device
+            .hardware_id()
+            .and_then(|id| HardwareId::try_from(id).map_err(SeesawError::InvalidHardwareId))?;
+        let product = device.product_info()?;
+        let capabilities = device.capabilities()?;
+
+        Ok(DeviceReport {
+            addr,
+            hardware_id,
+            product,
+            capabilities,
+            device: KnownDevice::identify(hardware_id, &product, &capabilities),
+        })
+    }
+}
+
+impl<D: Driver> Iterator for Scanner<'_, D> {
+    type Item = Result<DeviceReport, SeesawError<D::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(addr) = self.addrs.next() {
+            match self.probe(addr) {
+                Err(SeesawError::I2c(e)) if matches!(e.kind(), ErrorKind::NoAcknowledge(_)) => {}
+                Err(SeesawError::InvalidHardwareId(_)) => {}
+                result => return Some(result),
+            }
+        }
+        None
+    }
+}
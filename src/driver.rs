@@ -4,17 +4,13 @@ use embedded_hal::{
     i2c::{ErrorType, I2c, Operation, SevenBitAddress},
 };
 
-const DELAY_TIME: u32 = 125;
+pub(crate) const DELAY_TIME: u32 = 125;
 
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Debug)]
-pub struct SeesawDriver<I2C, DELAY>(DELAY, I2C);
+pub struct SeesawDriver<I2C, DELAY>(pub(crate) DELAY, pub(crate) I2C);
 
-impl<I2C, DELAY> SeesawDriver<I2C, DELAY>
-where
-    DELAY: DelayNs,
-    I2C: I2c,
-{
+impl<I2C, DELAY> SeesawDriver<I2C, DELAY> {
     pub fn new(delay: DELAY, i2c: I2C) -> Self {
         SeesawDriver(delay, i2c)
     }
@@ -22,8 +18,7 @@ where
 
 impl<DELAY, I2C> ErrorType for SeesawDriver<I2C, DELAY>
 where
-    DELAY: DelayNs,
-    I2C: I2c,
+    I2C: ErrorType,
 {
     type Error = I2C::Error;
 }
@@ -87,6 +82,14 @@ pub trait DriverExt {
         reg: &Reg,
     ) -> Result<[u8; N], Self::Error>;
 
+    /// Read as many bytes as fit in `buffer` from a register
+    fn register_read_into(
+        &mut self,
+        addr: SevenBitAddress,
+        reg: &Reg,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>;
+
     fn register_write(
         &mut self,
         addr: SevenBitAddress,
@@ -121,10 +124,19 @@ impl<T: Driver> DriverExt for T {
         reg: &Reg,
     ) -> Result<[u8; N], Self::Error> {
         let mut buffer = [0u8; N];
+        self.register_read_into(addr, reg, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    fn register_read_into(
+        &mut self,
+        addr: SevenBitAddress,
+        reg: &Reg,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
         self.write(addr, reg)?;
         self.delay_us(DELAY_TIME);
-        self.read(addr, &mut buffer)?;
-        Ok(buffer)
+        self.read(addr, buffer)
     }
 
     fn register_write(
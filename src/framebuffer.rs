@@ -0,0 +1,213 @@
+//! A host-side copy of a device's neopixels that only sends the pixels that
+//! changed since the last flush.
+//!
+//! ```rs
+//! let trellis = NeoTrellis::new_with_default_addr(seesaw).init()?;
+//! let mut fb = Framebuffer::<_, _, 16>::new(trellis);
+//! fb.fill(RGB8::new(0, 0, 32));
+//! fb.set_pixel(5, RGB8::new(255, 0, 0))?;
+//! let bytes_sent = fb.flush()?;
+//! ```
+//!
+//! Frames are passed through a [`ColorCorrection`] on their way out, which
+//! can dim them to stay within the power supply's budget:
+//!
+//! ```rs
+//! let mut fb = Framebuffer::<_, _, 16>::new(trellis)
+//!     .with_correction(ColorCorrection::default().with_current_limit(500));
+//! fb.fill(RGB8::new(255, 255, 255));
+//! fb.flush()?;
+//! assert!(fb.current_ma() <= 500);
+//! ```
+use crate::{
+    correction::ColorCorrection,
+    modules::neopixel::{check_pixels, max_colors_per_write, NeopixelColor, NeopixelModule},
+    Driver, SeesawError,
+};
+use core::marker::PhantomData;
+use rgb::RGBA8;
+
+/// The bytes of a `SET_BUF` write besides the colors: the register and the
+/// buffer offset
+const SET_BUF_OVERHEAD: usize = 4;
+/// The bytes of a `SHOW` write
+const SHOW_LEN: usize = 2;
+
+/// A framebuffer over the `N` neopixels of a device
+///
+/// Pixels are drawn with [`set_pixel`](Self::set_pixel) and
+/// [`fill`](Self::fill) and sent to the device with [`flush`](Self::flush),
+/// which compares them against a shadow copy of the device's pixel buffer
+/// and writes only the ranges of pixels that changed.
+///
+/// The shadow copy holds the pixels as they were written, after color
+/// correction, so changing the correction changes what the next flush sends.
+#[derive(Debug)]
+pub struct Framebuffer<D, T, const N: usize> {
+    correction: ColorCorrection,
+    device: T,
+    pixels: [RGBA8; N],
+    shadow: [RGBA8; N],
+    synced: bool,
+    _driver: PhantomData<D>,
+}
+
+impl<D: Driver, T: NeopixelModule<D>, const N: usize> Framebuffer<D, T, N> {
+    /// Draw to the first `N` neopixels of `device`. `N` is checked against
+    /// `N_LEDS` at compile time, so a device whose strip is configured at
+    /// runtime, like the NeoDriver, must also be given at least `N` pixels for
+    /// flushes to succeed.
+    ///
+    /// Every pixel starts off, and the first flush sends all of them.
+    pub fn new(device: T) -> Self {
+        const { assert!(N <= T::N_LEDS, "more pixels than the device has") };
+        Self {
+            correction: ColorCorrection::default(),
+            device,
+            pixels: [RGBA8::default(); N],
+            shadow: [RGBA8::default(); N],
+            synced: false,
+            _driver: PhantomData,
+        }
+    }
+
+    /// Set the color correction applied to each frame
+    pub fn with_correction(mut self, correction: ColorCorrection) -> Self {
+        self.correction = correction;
+        self
+    }
+
+    /// The color correction applied to each frame
+    pub fn correction(&self) -> &ColorCorrection {
+        &self.correction
+    }
+
+    /// Change the color correction applied to each frame
+    pub fn set_correction(&mut self, correction: ColorCorrection) {
+        self.correction = correction;
+    }
+
+    /// Change the brightness of each frame, where 255 is full brightness
+    pub fn set_brightness(&mut self, brightness: u8) {
+        self.correction.brightness = brightness;
+    }
+
+    /// The estimated current, in milliamps, drawn by the last flushed frame
+    pub fn current_ma(&self) -> u32 {
+        self.correction.current_ma(&self.shadow)
+    }
+
+    /// The device the framebuffer draws to
+    pub fn device(&mut self) -> &mut T {
+        &mut self.device
+    }
+
+    /// Give back the device
+    pub fn release(self) -> T {
+        self.device
+    }
+
+    /// The pixels as drawn, before color correction, with the alpha channel as
+    /// the white channel
+    pub fn pixels(&self) -> &[RGBA8; N] {
+        &self.pixels
+    }
+
+    /// The color of the nth pixel, or `None` if `n` is past the last pixel
+    pub fn get_pixel(&self, n: usize) -> Option<RGBA8> {
+        self.pixels.get(n).copied()
+    }
+
+    /// Set the color of the nth pixel
+    ///
+    /// Fails with [`SeesawError::NeopixelOutOfBounds`], like
+    /// [`NeopixelModule::set_nth_neopixel_color`], if `n` is past the last
+    /// pixel.
+    pub fn set_pixel<C: NeopixelColor>(
+        &mut self,
+        n: usize,
+        color: C,
+    ) -> Result<(), SeesawError<D::Error>> {
+        check_pixels(n, 1, N)?;
+        self.pixels[n] = color.to_rgbw();
+        Ok(())
+    }
+
+    /// The pixels as drawn, to be changed in place
+    #[cfg(feature = "device_neotrellis")]
+    pub(crate) fn pixels_mut(&mut self) -> &mut [RGBA8; N] {
+        &mut self.pixels
+    }
+
+    /// Set the color of every pixel
+    pub fn fill<C: NeopixelColor>(&mut self, color: C) {
+        self.pixels.fill(color.to_rgbw());
+    }
+
+    /// Whether any pixels have changed since the last flush
+    pub fn is_dirty(&self) -> bool {
+        !self.synced || self.frame() != self.shadow
+    }
+
+    /// Make the next flush send every pixel, e.g. after the device has been
+    /// reset or written to directly
+    pub fn invalidate(&mut self) {
+        self.synced = false;
+    }
+
+    /// Write the pixels that changed since the last flush to the device and
+    /// show them, returning the number of bytes written to the bus (not
+    /// counting I2C addressing)
+    ///
+    /// If nothing changed, nothing is written.
+    pub fn flush(&mut self) -> Result<usize, SeesawError<D::Error>> {
+        let frame = self.frame();
+        let mut sent = 0;
+        let mut n = 0;
+
+        while n < N {
+            if self.synced && frame[n] == self.shadow[n] {
+                n += 1;
+                continue;
+            }
+            let start = n;
+            while n < N && !(self.synced && frame[n] == self.shadow[n]) {
+                n += 1;
+            }
+            self.device
+                .set_neopixel_colors_from(start, &frame[start..n])?;
+            self.shadow[start..n].copy_from_slice(&frame[start..n]);
+            sent += set_buf_len(self.c_size(), n - start);
+        }
+
+        if sent == 0 {
+            return Ok(0);
+        }
+        self.synced = true;
+        self.device.sync_neopixel()?;
+        Ok(sent + SHOW_LEN)
+    }
+
+    /// The pixels as they'll be written, after color correction. The white
+    /// channel is dropped first for RGB neopixels so it doesn't count towards
+    /// the current limit.
+    fn frame(&self) -> [RGBA8; N] {
+        let mut frame = self.pixels;
+        if self.c_size() < 4 {
+            frame.iter_mut().for_each(|c| c.a = 0);
+        }
+        self.correction.apply(&mut frame);
+        frame
+    }
+
+    /// The size of each pixel in bytes
+    fn c_size(&self) -> usize {
+        self.device.neopixel_color_order().bytes_per_pixel()
+    }
+}
+
+/// The number of bytes it takes to write `len` colors of `c_size` bytes each
+/// through `SET_BUF`
+fn set_buf_len(c_size: usize, len: usize) -> usize {
+    len.div_ceil(max_colors_per_write(c_size)) * SET_BUF_OVERHEAD + len * c_size
+}
@@ -0,0 +1,250 @@
+//! Taps, double taps, long presses, holds, auto-repeat and chords, recognized
+//! from the raw pressed and released states of keys and buttons.
+//!
+//! A [`GestureRecognizer`] is fed key states, either as [`KeyEvent`]s or from
+//! polling buttons, and timestamps from a [`Clock`]. Gestures that depend on
+//! time passing, like holds, are recognized by calling
+//! [`tick`](GestureRecognizer::tick) regularly:
+//!
+//! ```rs
+//! let mut gestures = GestureRecognizer::<_, 16>::new(|| timer.now().ticks(), 4);
+//! loop {
+//!     for event in trellis.read_key_events()? {
+//!         gestures.key_event(event, |g| handle(g));
+//!     }
+//!     gestures.tick(|g| handle(g));
+//! }
+//! ```
+//!
+//! Buttons that are polled are fed by their position:
+//!
+//! ```rs
+//! for (x, pressed) in arcade.button_values()?.into_iter().enumerate() {
+//!     gestures.update(x as u8, 0, pressed, |g| handle(g));
+//! }
+//! ```
+use crate::buttons::{KeyEvent, KeyEventType};
+
+/// A monotonic source of timestamps, in milliseconds
+///
+/// A clock that goes backwards, e.g. because it wrapped or was reset, doesn't
+/// panic; time just stands still for the keys already down or released until
+/// it catches up.
+pub trait Clock {
+    fn now_ms(&self) -> u64;
+}
+
+impl<F: Fn() -> u64> Clock for F {
+    fn now_ms(&self) -> u64 {
+        self()
+    }
+}
+
+/// A recognized gesture, and the key that made it
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Gesture {
+    pub kind: GestureKind,
+    pub x: u8,
+    pub y: u8,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum GestureKind {
+    /// The key was pressed and released quickly, and not pressed again within
+    /// the double tap window
+    Tap,
+    /// The key was pressed again within the double tap window of a tap. The
+    /// release that follows isn't reported as a tap.
+    DoubleTap,
+    /// The key has been held down for the long press time
+    Hold,
+    /// The key was released after being held down for the long press time
+    LongPress,
+    /// The key is still held down, after the repeat delay and then every
+    /// repeat interval
+    Repeat,
+    /// The key was pressed while others were held down. Holds the keys now
+    /// held down, as a bitmask of `y * cols + x`.
+    Chord(u64),
+}
+
+/// The timing thresholds for recognizing a key's gestures
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct GestureConfig {
+    /// How long after a tap a second press counts as a double tap. With 0,
+    /// double taps aren't recognized and taps are reported on release.
+    pub double_tap_ms: u32,
+    /// How long a key is held down before it's a hold, and its release a long
+    /// press
+    pub long_press_ms: u32,
+    /// How long a key is held down before it starts repeating
+    pub repeat_delay_ms: u32,
+    /// How often a held key repeats. With 0, keys don't repeat.
+    pub repeat_interval_ms: u32,
+}
+
+/// 250ms double taps and 500ms long presses, without auto-repeat
+impl Default for GestureConfig {
+    fn default() -> Self {
+        Self {
+            double_tap_ms: 250,
+            long_press_ms: 500,
+            repeat_delay_ms: 500,
+            repeat_interval_ms: 0,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+struct KeyState {
+    pressed: bool,
+    pressed_at: u64,
+    released_at: u64,
+    /// A tap waiting to see if it becomes a double tap
+    tap_pending: bool,
+    /// Whether the hold of the current press has been reported
+    held: bool,
+    /// Whether the release of the current press is part of a double tap
+    double_tapped: bool,
+    next_repeat: u64,
+}
+
+/// Recognizes the gestures of `N` keys laid out in rows of `cols`
+#[derive(Debug)]
+pub struct GestureRecognizer<C, const N: usize> {
+    clock: C,
+    cols: u8,
+    configs: [GestureConfig; N],
+    keys: [KeyState; N],
+}
+
+impl<C: Clock, const N: usize> GestureRecognizer<C, N> {
+    /// Recognize the gestures of `N` keys, at most 64, laid out in rows of
+    /// `cols`, timed by `clock`
+    pub fn new(clock: C, cols: u8) -> Self {
+        assert!(N <= 64, "more than 64 keys");
+        assert!(cols > 0, "no columns");
+        Self {
+            clock,
+            cols,
+            configs: [GestureConfig::default(); N],
+            keys: [KeyState::default(); N],
+        }
+    }
+
+    /// Use `config` for every key
+    pub fn with_config(mut self, config: GestureConfig) -> Self {
+        self.configs = [config; N];
+        self
+    }
+
+    /// Use `config` for the key at `(x, y)`. Keys outside the grid are
+    /// ignored.
+    pub fn set_key_config(&mut self, x: u8, y: u8, config: GestureConfig) {
+        if let Some(i) = self.index(x, y) {
+            self.configs[i] = config;
+        }
+    }
+
+    /// The keys held down, as a bitmask of `y * cols + x`
+    pub fn held_keys(&self) -> u64 {
+        self.keys
+            .iter()
+            .enumerate()
+            .filter(|(_, key)| key.pressed)
+            .fold(0, |mask, (i, _)| mask | (1 << i))
+    }
+
+    /// Feed an event read from a keypad or a
+    /// [`ButtonTracker`](crate::buttons::ButtonTracker). Events for keys
+    /// outside the grid only advance time.
+    pub fn key_event<F: FnMut(Gesture)>(&mut self, event: KeyEvent, f: F) {
+        let pressed = matches!(event.event, KeyEventType::Pressed | KeyEventType::IsPressed);
+        self.update(event.x, event.y, pressed, f);
+    }
+
+    /// Feed the state of the key at `(x, y)`, passing any gestures it
+    /// completes to `f`. States that haven't changed since the last update,
+    /// and keys outside the grid, only advance time.
+    pub fn update<F: FnMut(Gesture)>(&mut self, x: u8, y: u8, pressed: bool, mut f: F) {
+        self.tick(&mut f);
+        let now = self.clock.now_ms();
+        let Some(i) = self.index(x, y) else {
+            return;
+        };
+        let config = self.configs[i];
+        let key = &mut self.keys[i];
+        let gesture = |kind| Gesture { kind, x, y };
+
+        match (key.pressed, pressed) {
+            (false, true) => {
+                key.pressed = true;
+                key.pressed_at = now;
+                key.held = false;
+                key.next_repeat = now.saturating_add(config.repeat_delay_ms.into());
+                if key.tap_pending {
+                    key.tap_pending = false;
+                    key.double_tapped = true;
+                    f(gesture(GestureKind::DoubleTap));
+                }
+                let held = self.held_keys();
+                if held.count_ones() > 1 {
+                    f(gesture(GestureKind::Chord(held)));
+                }
+            }
+            (true, false) => {
+                key.pressed = false;
+                key.released_at = now;
+                if core::mem::take(&mut key.double_tapped) {
+                    return;
+                }
+                if now.saturating_sub(key.pressed_at) >= config.long_press_ms.into() {
+                    f(gesture(GestureKind::LongPress));
+                } else if config.double_tap_ms == 0 {
+                    f(gesture(GestureKind::Tap));
+                } else {
+                    key.tap_pending = true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Pass the gestures that have been completed by time passing to `f`
+    pub fn tick<F: FnMut(Gesture)>(&mut self, mut f: F) {
+        let now = self.clock.now_ms();
+        let cols = self.cols;
+
+        for (i, (key, config)) in self.keys.iter_mut().zip(&self.configs).enumerate() {
+            let gesture = |kind| Gesture {
+                kind,
+                x: i as u8 % cols,
+                y: i as u8 / cols,
+            };
+            if key.pressed {
+                if !key.held && now.saturating_sub(key.pressed_at) >= config.long_press_ms.into() {
+                    key.held = true;
+                    f(gesture(GestureKind::Hold));
+                }
+                if config.repeat_interval_ms > 0 && now >= key.next_repeat {
+                    key.next_repeat = now.saturating_add(config.repeat_interval_ms.into());
+                    f(gesture(GestureKind::Repeat));
+                }
+            } else if key.tap_pending
+                && now.saturating_sub(key.released_at) > config.double_tap_ms.into()
+            {
+                key.tap_pending = false;
+                f(gesture(GestureKind::Tap));
+            }
+        }
+    }
+
+    /// The index of the key at `(x, y)`, if it's in the grid
+    fn index(&self, x: u8, y: u8) -> Option<usize> {
+        let i = y as usize * self.cols as usize + x as usize;
+        (x < self.cols && i < N).then_some(i)
+    }
+}
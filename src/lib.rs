@@ -1,17 +1,37 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
-#![allow(const_evaluatable_unchecked, incomplete_features, rustdoc::bare_urls)]
-#![cfg_attr(feature = "module_neopixel", feature(generic_const_exprs))]
+#![allow(rustdoc::bare_urls)]
 
 use core::fmt::{Display, Formatter};
 // Re-export rgb
 pub use rgb;
 
+#[cfg(feature = "async")]
+pub mod asynch;
+pub mod buttons;
+#[cfg(feature = "module_neopixel")]
+pub mod correction;
 pub mod devices;
+#[cfg(feature = "module_gpio")]
+pub mod digital;
+pub mod discovery;
+#[cfg(feature = "module_neopixel")]
+pub mod framebuffer;
+pub mod gesture;
+#[cfg(feature = "mock")]
+pub mod mock;
 pub mod modules;
+#[cfg(feature = "module_timer")]
+pub mod pwm;
+#[cfg(feature = "module_timer")]
+pub mod servo;
+#[cfg(feature = "smart_leds")]
+pub mod smart_leds;
 pub mod prelude {
     #[cfg(feature = "module_adc")]
     pub use super::modules::adc::*;
+    #[cfg(feature = "module_eeprom")]
+    pub use super::modules::eeprom::*;
     #[cfg(feature = "module_encoder")]
     pub use super::modules::encoder::*;
     #[cfg(feature = "module_gpio")]
@@ -22,7 +42,10 @@ pub mod prelude {
     pub use super::modules::neopixel::*;
     #[cfg(feature = "module_timer")]
     pub use super::modules::timer::*;
+    #[cfg(feature = "module_touch")]
+    pub use super::modules::touch::*;
     pub use super::{
+        buttons::*,
         devices::{SeesawDevice, SeesawDeviceInit},
         driver::{DriverExt, SeesawDriver},
         modules::{status::*, HardwareId},
@@ -38,6 +61,21 @@ pub enum SeesawError<E> {
     I2c(E),
     /// Occurs when an invalid hardware ID is read
     InvalidHardwareId(u8),
+    /// Occurs when a pin is set to a mode the seesaw firmware can't configure
+    UnsupportedPinMode(u8),
+    /// Occurs when an EEPROM access would end at byte `end`, past the `size`
+    /// bytes it may touch. Writes may not touch the last byte, which holds the
+    /// device's I2C address.
+    EepromOutOfBounds { end: usize, size: usize },
+    /// Occurs when a NeoPixel strip would take up `len` bytes, more than the
+    /// `max` bytes the firmware's pixel buffer holds
+    NeopixelBufferOverflow { len: usize, max: usize },
+    /// Occurs when writing NeoPixel colors would end at pixel `end`, past the
+    /// `len` pixels of the strip
+    NeopixelOutOfBounds { end: usize, len: usize },
+    /// Occurs when a device is given an I2C address outside of the
+    /// non-reserved 7-bit range
+    InvalidAddress(u8),
 }
 
 impl<E> Display for SeesawError<E> {
@@ -45,6 +83,23 @@ impl<E> Display for SeesawError<E> {
         match self {
             SeesawError::I2c(_) => f.write_str("I2C error"),
             SeesawError::InvalidHardwareId(id) => write!(f, "invalid hardware id: {id}"),
+            SeesawError::UnsupportedPinMode(mode) => write!(f, "unsupported pin mode: {mode:#04x}"),
+            SeesawError::NeopixelBufferOverflow { len, max } => {
+                write!(
+                    f,
+                    "{len} bytes of pixels exceed the {max} byte NeoPixel buffer"
+                )
+            }
+            SeesawError::NeopixelOutOfBounds { end, len } => {
+                write!(f, "pixels up to {end} exceed the {len} pixel strip")
+            }
+            SeesawError::InvalidAddress(addr) => write!(f, "invalid I2C address: {addr:#04x}"),
+            SeesawError::EepromOutOfBounds { end, size } => {
+                write!(
+                    f,
+                    "EEPROM access ending at byte {end} exceeds its size of {size} bytes"
+                )
+            }
         }
     }
 }
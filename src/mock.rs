@@ -0,0 +1,777 @@
+//! An in-memory, register-level emulator of a seesaw device.
+//!
+//! [`SeesawEmulator`] implements `embedded_hal`'s `I2c` and `DelayNs` (and
+//! their `embedded-hal-async` twins when the `async` feature is enabled), so
+//! it can be handed to any device constructor in place of a real
+//! [`SeesawDriver`](crate::SeesawDriver). This makes it possible to exercise
+//! the module traits on a host machine, e.g. in CI:
+//!
+//! ```rust
+//! use adafruit_seesaw::{devices::NeoKey1x4, mock::SeesawEmulator, prelude::*};
+//!
+//! let emulator = SeesawEmulator::for_device::<NeoKey1x4<SeesawEmulator>>();
+//! let mut neokeys = NeoKey1x4::new_with_default_addr(emulator).init().unwrap();
+//! assert!(neokeys.driver().is_pulled_up(4));
+//!
+//! neokeys.driver().set_pin_level(4, false);
+//! assert_eq!(neokeys.keys().unwrap(), 0b1110);
+//! ```
+//!
+//! The emulator models the Status, GPIO, ADC, Timer, EEPROM, Touch, Encoder,
+//! Keypad and NeoPixel register maps. Registers it doesn't model read back as
+//! zeros and ignore writes.
+use crate::{devices::SeesawDevice, modules::HardwareId};
+use embedded_hal::{
+    delay::DelayNs,
+    i2c::{ErrorKind, ErrorType, I2c, NoAcknowledgeSource, Operation, SevenBitAddress},
+};
+
+/// The size of the seesaw firmware's I2C receive buffer, plus the two
+/// register bytes
+const RX_BUF_SIZE: usize = 2 + 32;
+/// The size of the emulated NeoPixel buffer in bytes
+pub const NEOPIXEL_BUF_SIZE: usize = 512;
+/// The number of emulated encoders
+pub const MAX_ENCODERS: usize = 16;
+/// The number of emulated ADC channels
+pub const MAX_ADC_CHANNELS: usize = 32;
+/// The number of emulated touch channels
+pub const MAX_TOUCH_CHANNELS: usize = 16;
+/// The capacity of the emulated keypad event FIFO
+pub const KEYPAD_FIFO_SIZE: usize = 32;
+
+const STATUS: u8 = 0x00;
+const GPIO: u8 = 0x01;
+const TIMER: u8 = 0x08;
+const ADC: u8 = 0x09;
+const EEPROM: u8 = 0x0D;
+const NEOPIXEL: u8 = 0x0E;
+const TOUCH: u8 = 0x0F;
+const KEYPAD: u8 = 0x10;
+
+/// The raw `IsPressed` and `IsReleased` key event types
+const KEY_IS_PRESSED: u8 = 0;
+const KEY_IS_RELEASED: u8 = 1;
+const ENCODER: u8 = 0x11;
+
+/// The modules reported in `STATUS_OPTIONS` by default
+const DEFAULT_OPTIONS: u32 = (1 << STATUS)
+    | (1 << GPIO)
+    | (1 << TIMER)
+    | (1 << ADC)
+    | (1 << EEPROM)
+    | (1 << NEOPIXEL)
+    | (1 << TOUCH)
+    | (1 << KEYPAD)
+    | (1 << ENCODER);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MockError {
+    /// Nothing is listening at the address of the transaction
+    Nack(SevenBitAddress),
+    /// A write was larger than the seesaw's 32 byte receive buffer
+    Overrun(usize),
+}
+
+impl core::fmt::Display for MockError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MockError::Nack(addr) => write!(f, "no device at address {addr:#04x}"),
+            MockError::Overrun(len) => write!(f, "write of {len} bytes overruns receive buffer"),
+        }
+    }
+}
+
+impl core::error::Error for MockError {}
+
+impl embedded_hal::i2c::Error for MockError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            MockError::Nack(_) => ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address),
+            MockError::Overrun(_) => ErrorKind::Overrun,
+        }
+    }
+}
+
+/// The direction of an enabled pull resistor
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Pull {
+    Up,
+    Down,
+}
+
+/// Everything a software reset puts back to its default value
+#[derive(Clone, Debug)]
+struct Registers {
+    /// The register selected by the most recent write, read back by the next
+    /// read
+    selected: [u8; 2],
+    gpio_direction: u32,
+    gpio_output: u32,
+    gpio_pull: u32,
+    gpio_int_enable: u32,
+    gpio_int_flag: u32,
+    pwm: [u16; 32],
+    pwm_freq: [u16; 32],
+    encoder_position: [i32; MAX_ENCODERS],
+    encoder_delta: [i32; MAX_ENCODERS],
+    encoder_int: u16,
+    keypad_triggers: [u8; 64],
+    keypad_int: bool,
+    keypad_fifo: [u8; KEYPAD_FIFO_SIZE],
+    keypad_fifo_len: usize,
+    neopixel_pin: Option<u8>,
+    neopixel_speed: u8,
+    neopixel_len: u16,
+    neopixel_buf: [u8; NEOPIXEL_BUF_SIZE],
+    neopixel_shown: [u8; NEOPIXEL_BUF_SIZE],
+    neopixel_shows: u32,
+}
+
+impl Default for Registers {
+    fn default() -> Self {
+        Self {
+            selected: [0; 2],
+            gpio_direction: 0,
+            gpio_output: 0,
+            gpio_pull: 0,
+            gpio_int_enable: 0,
+            gpio_int_flag: 0,
+            pwm: [0; 32],
+            pwm_freq: [0; 32],
+            encoder_position: [0; MAX_ENCODERS],
+            encoder_delta: [0; MAX_ENCODERS],
+            encoder_int: 0,
+            keypad_triggers: [0; 64],
+            keypad_int: false,
+            keypad_fifo: [0; KEYPAD_FIFO_SIZE],
+            keypad_fifo_len: 0,
+            neopixel_pin: None,
+            neopixel_speed: 1,
+            neopixel_len: 0,
+            neopixel_buf: [0; NEOPIXEL_BUF_SIZE],
+            neopixel_shown: [0; NEOPIXEL_BUF_SIZE],
+            neopixel_shows: 0,
+        }
+    }
+}
+
+/// A register-level emulator of a seesaw device.
+///
+/// See the [module documentation](self) for an example.
+#[derive(Clone, Debug)]
+pub struct SeesawEmulator {
+    addr: SevenBitAddress,
+    hardware_id: HardwareId,
+    product_id: u16,
+    date_code: u16,
+    options: u32,
+    pin_count: u8,
+    /// Levels driven onto the input pins by the outside world
+    pin_levels: u32,
+    /// Which input pins are being driven by the outside world
+    pin_driven: u32,
+    /// The keypad keys held down by the outside world, by `y << 3 | x`
+    keys_held: u64,
+    adc: [u16; MAX_ADC_CHANNELS],
+    eeprom: [u8; 256],
+    touch: [u16; MAX_TOUCH_CHANNELS],
+    temp: u32,
+    elapsed_ns: u64,
+    resets: u32,
+    regs: Registers,
+}
+
+impl SeesawEmulator {
+    /// Create an emulator that responds at `addr` and identifies itself with
+    /// the given hardware and product IDs
+    pub fn new(addr: SevenBitAddress, hardware_id: HardwareId, product_id: u16) -> Self {
+        Self {
+            addr,
+            hardware_id,
+            product_id,
+            date_code: 0,
+            options: DEFAULT_OPTIONS,
+            pin_count: 32,
+            pin_levels: 0,
+            pin_driven: 0,
+            keys_held: 0,
+            adc: [0; MAX_ADC_CHANNELS],
+            eeprom: [0xFF; 256],
+            touch: [0; MAX_TOUCH_CHANNELS],
+            temp: 0,
+            elapsed_ns: 0,
+            resets: 0,
+            regs: Registers::default(),
+        }
+    }
+
+    /// Create an emulator that poses as the device `T` at its default address
+    pub fn for_device<T: SeesawDevice>() -> Self {
+        Self::new(T::DEFAULT_ADDR, T::HARDWARE_ID, T::PRODUCT_ID)
+    }
+
+    /// Respond at a different address
+    pub fn with_addr(mut self, addr: SevenBitAddress) -> Self {
+        self.addr = addr;
+        self
+    }
+
+    /// Limit the number of GPIO pins; writes to pins beyond the count are
+    /// ignored and they always read low
+    pub fn with_pin_count(mut self, pin_count: u8) -> Self {
+        self.pin_count = pin_count.min(32);
+        self
+    }
+
+    /// Set the raw `STATUS_OPTIONS` bitmask reported as the device's
+    /// capabilities
+    pub fn with_options(mut self, options: u32) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Set the lower 16 bits of `STATUS_VERSION`, which encode the product's
+    /// date code
+    pub fn with_date_code(mut self, date_code: u16) -> Self {
+        self.date_code = date_code;
+        self
+    }
+
+    pub fn addr(&self) -> SevenBitAddress {
+        self.addr
+    }
+
+    /// The total time spent in delays, in nanoseconds
+    pub fn elapsed_ns(&self) -> u64 {
+        self.elapsed_ns
+    }
+
+    /// The number of software resets the device has received
+    pub fn resets(&self) -> u32 {
+        self.resets
+    }
+
+    /// Whether the device is asserting its IRQ line
+    pub fn interrupt_pending(&self) -> bool {
+        let regs = &self.regs;
+        let encoders = (0..MAX_ENCODERS)
+            .any(|i| (regs.encoder_int >> i) & 1 == 1 && regs.encoder_delta[i] != 0);
+        regs.gpio_int_flag & regs.gpio_int_enable != 0
+            || (regs.keypad_int && regs.keypad_fifo_len > 0)
+            || encoders
+    }
+
+    // Status
+
+    /// Set the temperature reported by `STATUS_TEMP`, in degrees Celsius
+    pub fn set_temperature(&mut self, celsius: f32) {
+        self.temp = (celsius * (1u32 << 16) as f32) as u32;
+    }
+
+    // GPIO
+
+    /// Drive an input pin from the outside world. Pins beyond the pin count
+    /// are ignored.
+    pub fn set_pin_level(&mut self, pin: u8, high: bool) {
+        let before = self.gpio_levels();
+        let bit = pin_bit(pin);
+        self.pin_driven |= bit & self.pin_mask();
+        if high {
+            self.pin_levels |= bit;
+        } else {
+            self.pin_levels &= !bit;
+        }
+        self.latch_pin_changes(before);
+    }
+
+    /// Stop driving an input pin, leaving it to its pull resistor
+    pub fn release_pin(&mut self, pin: u8) {
+        let before = self.gpio_levels();
+        self.pin_driven &= !pin_bit(pin);
+        self.latch_pin_changes(before);
+    }
+
+    /// Whether the pin is configured as an output
+    pub fn is_output(&self, pin: u8) -> bool {
+        self.regs.gpio_direction & pin_bit(pin) != 0
+    }
+
+    /// The level the pin reads as, as seen through the `GPIO` register
+    pub fn pin_level(&self, pin: u8) -> bool {
+        self.gpio_levels() & pin_bit(pin) != 0
+    }
+
+    /// The direction of the pin's pull resistor, if enabled
+    pub fn pull(&self, pin: u8) -> Option<Pull> {
+        let bit = pin_bit(pin);
+        match (
+            self.regs.gpio_pull & bit != 0,
+            self.regs.gpio_output & bit != 0,
+        ) {
+            (true, true) => Some(Pull::Up),
+            (true, false) => Some(Pull::Down),
+            _ => None,
+        }
+    }
+
+    pub fn is_pulled_up(&self, pin: u8) -> bool {
+        self.pull(pin) == Some(Pull::Up)
+    }
+
+    pub fn is_pulled_down(&self, pin: u8) -> bool {
+        self.pull(pin) == Some(Pull::Down)
+    }
+
+    /// Whether pin-change interrupts are enabled for the pin
+    pub fn is_pin_interrupt_enabled(&self, pin: u8) -> bool {
+        self.regs.gpio_int_enable & pin_bit(pin) != 0
+    }
+
+    // ADC
+
+    /// Set the value read from an ADC channel. Note that this is the index of
+    /// the channel's register, which on SAMD09 devices isn't the pin number.
+    /// Channels beyond [`MAX_ADC_CHANNELS`] are ignored.
+    pub fn set_adc_channel(&mut self, channel: u8, value: u16) {
+        if let Some(adc) = self.adc.get_mut(channel as usize) {
+            *adc = value;
+        }
+    }
+
+    // Timer
+
+    /// The 16 bit duty cycle last written to a PWM output. Note that this is
+    /// the index of the PWM output, which on SAMD09 devices isn't the pin
+    /// number.
+    pub fn pwm(&self, pwm: u8) -> u16 {
+        self.regs.pwm.get(pwm as usize).copied().unwrap_or(0)
+    }
+
+    /// The frequency in Hz last written to a PWM output, or 0 if it hasn't
+    /// been set since the last reset
+    pub fn pwm_freq(&self, pwm: u8) -> u16 {
+        self.regs.pwm_freq.get(pwm as usize).copied().unwrap_or(0)
+    }
+
+    // EEPROM
+
+    /// The contents of the EEPROM, which like the real thing survives
+    /// software resets and starts out erased to `0xFF`
+    pub fn eeprom(&self) -> &[u8] {
+        &self.eeprom[..self.hardware_id.eeprom_size()]
+    }
+
+    /// Mutable access to the contents of the EEPROM
+    pub fn eeprom_mut(&mut self) -> &mut [u8] {
+        &mut self.eeprom[..self.hardware_id.eeprom_size()]
+    }
+
+    // Touch
+
+    /// Set the capacitive value read from a touch channel. Channels beyond
+    /// [`MAX_TOUCH_CHANNELS`] are ignored.
+    pub fn set_touch_channel(&mut self, channel: u8, value: u16) {
+        if let Some(touch) = self.touch.get_mut(channel as usize) {
+            *touch = value;
+        }
+    }
+
+    // Encoder
+
+    /// Turn an encoder by `steps` detents (negative for counter-clockwise).
+    /// Encoders beyond [`MAX_ENCODERS`] are ignored.
+    pub fn turn_encoder(&mut self, encoder: usize, steps: i32) {
+        if encoder < MAX_ENCODERS {
+            let regs = &mut self.regs;
+            regs.encoder_position[encoder] = regs.encoder_position[encoder].wrapping_add(steps);
+            regs.encoder_delta[encoder] = regs.encoder_delta[encoder].wrapping_add(steps);
+        }
+    }
+
+    pub fn encoder_position(&self, encoder: usize) -> i32 {
+        self.regs
+            .encoder_position
+            .get(encoder)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn is_encoder_interrupt_enabled(&self, encoder: usize) -> bool {
+        encoder < MAX_ENCODERS && (self.regs.encoder_int >> encoder) & 1 == 1
+    }
+
+    // Keypad
+
+    /// Record a key event, as if a key at `(x, y)` changed state.
+    ///
+    /// The event is only added to the FIFO if it has been enabled with
+    /// `set_key_event_triggers`. Returns whether the event was recorded, which
+    /// it never is for keys outside the keypad's 8x8 grid.
+    #[cfg(feature = "module_keypad")]
+    pub fn key_event(&mut self, x: u8, y: u8, event: crate::modules::keypad::KeyEventType) -> bool {
+        let Some(key) = key_index(x, y) else {
+            return false;
+        };
+        let edge = event as u8;
+        if (self.regs.keypad_triggers[key] >> (edge + 1)) & 1 == 0 {
+            return false;
+        }
+        self.push_key_event(((key as u8) << 2) | edge)
+    }
+
+    /// Hold the key at `(x, y)` down, or let it up. Keys outside the keypad's
+    /// 8x8 grid are ignored.
+    ///
+    /// Held keys with the `IsPressed` trigger enabled, and keys that are up
+    /// with the `IsReleased` trigger enabled, add an event every time the
+    /// keypad is scanned. The firmware scans continuously, so the FIFO is
+    /// refilled as soon as it's read.
+    pub fn hold_key(&mut self, x: u8, y: u8, held: bool) {
+        if let Some(key) = key_index(x, y) {
+            match held {
+                true => self.keys_held |= 1 << key,
+                false => self.keys_held &= !(1 << key),
+            }
+        }
+    }
+
+    /// Add the events of the level triggers, as the firmware does on every
+    /// scan of the keypad
+    fn scan_keypad(&mut self) {
+        for key in 0..64 {
+            let level = match (self.keys_held >> key) & 1 {
+                1 => KEY_IS_PRESSED,
+                _ => KEY_IS_RELEASED,
+            };
+            if (self.regs.keypad_triggers[key] >> (level + 1)) & 1 == 1 {
+                self.push_key_event(((key as u8) << 2) | level);
+            }
+        }
+    }
+
+    /// Push a raw event byte onto the keypad FIFO, regardless of the enabled
+    /// triggers. Returns `false` if the FIFO is full.
+    pub fn push_key_event(&mut self, raw: u8) -> bool {
+        let regs = &mut self.regs;
+        if regs.keypad_fifo_len == KEYPAD_FIFO_SIZE {
+            return false;
+        }
+        regs.keypad_fifo[regs.keypad_fifo_len] = raw;
+        regs.keypad_fifo_len += 1;
+        true
+    }
+
+    /// The number of events waiting in the keypad FIFO
+    pub fn pending_key_events(&self) -> usize {
+        self.regs.keypad_fifo_len
+    }
+
+    /// The raw trigger bits enabled for a key, 0 for keys outside the
+    /// keypad's 8x8 grid
+    pub fn key_triggers(&self, x: u8, y: u8) -> u8 {
+        key_index(x, y).map_or(0, |key| self.regs.keypad_triggers[key])
+    }
+
+    pub fn is_keypad_interrupt_enabled(&self) -> bool {
+        self.regs.keypad_int
+    }
+
+    // NeoPixel
+
+    /// The pin set with `SET_PIN`, if any
+    pub fn neopixel_pin(&self) -> Option<u8> {
+        self.regs.neopixel_pin
+    }
+
+    /// The raw value of `SET_SPEED`
+    pub fn neopixel_speed(&self) -> u8 {
+        self.regs.neopixel_speed
+    }
+
+    /// The pixel buffer length in bytes, as set with `SET_LEN`
+    pub fn neopixel_len(&self) -> usize {
+        self.regs.neopixel_len as usize
+    }
+
+    /// The contents of the pixel buffer, including writes that haven't been
+    /// shown yet
+    pub fn neopixel_buffer(&self) -> &[u8] {
+        &self.regs.neopixel_buf[..self.neopixel_len().min(NEOPIXEL_BUF_SIZE)]
+    }
+
+    /// The contents of the pixel buffer as of the most recent `SHOW`
+    pub fn neopixel_shown(&self) -> &[u8] {
+        &self.regs.neopixel_shown[..self.neopixel_len().min(NEOPIXEL_BUF_SIZE)]
+    }
+
+    /// The number of `SHOW` commands received
+    pub fn neopixel_shows(&self) -> u32 {
+        self.regs.neopixel_shows
+    }
+
+    fn pin_mask(&self) -> u32 {
+        match self.pin_count {
+            32 => u32::MAX,
+            n => (1 << n) - 1,
+        }
+    }
+
+    fn gpio_levels(&self) -> u32 {
+        let regs = &self.regs;
+        let pulled = regs.gpio_pull & regs.gpio_output;
+        let inputs = (self.pin_driven & self.pin_levels) | (!self.pin_driven & pulled);
+        ((regs.gpio_direction & regs.gpio_output) | (!regs.gpio_direction & inputs))
+            & self.pin_mask()
+    }
+
+    fn latch_pin_changes(&mut self, before: u32) {
+        let changed = (before ^ self.gpio_levels()) & !self.regs.gpio_direction;
+        self.regs.gpio_int_flag |= changed & self.regs.gpio_int_enable;
+    }
+
+    fn write_register(&mut self, reg: [u8; 2], data: &[u8]) {
+        self.regs.selected = reg;
+        let u32_value = || {
+            let mut bytes = [0; 4];
+            let n = data.len().min(4);
+            bytes[..n].copy_from_slice(&data[..n]);
+            u32::from_be_bytes(bytes)
+        };
+
+        match reg {
+            [STATUS, 0x7F] if !data.is_empty() => {
+                self.regs = Registers::default();
+                self.resets += 1;
+                // The firmware boots at the address stored in the last byte of
+                // the EEPROM, if one has been written there
+                if let addr @ 0..=0x7F = self.eeprom()[self.hardware_id.eeprom_size() - 1] {
+                    self.addr = addr;
+                }
+            }
+            [GPIO, func] if !data.is_empty() => {
+                let before = self.gpio_levels();
+                let pins = u32_value() & self.pin_mask();
+                let regs = &mut self.regs;
+                match func {
+                    0x02 => regs.gpio_direction |= pins,
+                    0x03 => regs.gpio_direction &= !pins,
+                    0x04 => regs.gpio_output = pins,
+                    0x05 => regs.gpio_output |= pins,
+                    0x06 => regs.gpio_output &= !pins,
+                    0x07 => regs.gpio_output ^= pins,
+                    0x08 => regs.gpio_int_enable |= pins,
+                    0x09 => regs.gpio_int_enable &= !pins,
+                    0x0B => regs.gpio_pull |= pins,
+                    0x0C => regs.gpio_pull &= !pins,
+                    _ => {}
+                }
+                self.latch_pin_changes(before);
+            }
+            [TIMER, 0x01] if data.len() >= 2 => {
+                let value = u16::from_be_bytes([data[1], data.get(2).copied().unwrap_or(0)]);
+                if let Some(pwm) = self.regs.pwm.get_mut(data[0] as usize) {
+                    *pwm = value;
+                }
+            }
+            [TIMER, 0x02] if data.len() >= 3 => {
+                if let Some(freq) = self.regs.pwm_freq.get_mut(data[0] as usize) {
+                    *freq = u16::from_be_bytes([data[1], data[2]]);
+                }
+            }
+            [EEPROM, offset] => {
+                let eeprom = &mut self.eeprom[..self.hardware_id.eeprom_size()];
+                let start = (offset as usize).min(eeprom.len());
+                let n = data.len().min(eeprom.len() - start);
+                eeprom[start..start + n].copy_from_slice(&data[..n]);
+            }
+            [ENCODER, func] if !data.is_empty() => {
+                let encoder = (func & 0x0F) as usize;
+                match func & 0xF0 {
+                    0x10 => self.regs.encoder_int |= 1 << encoder,
+                    0x20 => self.regs.encoder_int &= !(1 << encoder),
+                    0x30 => self.regs.encoder_position[encoder] = u32_value() as i32,
+                    _ => {}
+                }
+            }
+            [KEYPAD, 0x01] if data.len() >= 2 => {
+                let trigger = &mut self.regs.keypad_triggers[(data[0] & 0x3F) as usize];
+                let edges = data[1] & 0x1E;
+                if data[1] & 1 == 1 {
+                    *trigger |= edges;
+                } else {
+                    *trigger &= !edges;
+                }
+            }
+            [KEYPAD, 0x02] if !data.is_empty() => self.regs.keypad_int = true,
+            [KEYPAD, 0x03] if !data.is_empty() => self.regs.keypad_int = false,
+            [NEOPIXEL, 0x01] if !data.is_empty() => self.regs.neopixel_pin = Some(data[0]),
+            [NEOPIXEL, 0x02] if !data.is_empty() => self.regs.neopixel_speed = data[0],
+            [NEOPIXEL, 0x03] if data.len() >= 2 => {
+                self.regs.neopixel_len = u16::from_be_bytes([data[0], data[1]]);
+            }
+            [NEOPIXEL, 0x04] if data.len() >= 2 => {
+                let offset = u16::from_be_bytes([data[0], data[1]]) as usize;
+                let pixels = &data[2..];
+                let end = (offset + pixels.len()).min(NEOPIXEL_BUF_SIZE);
+                if offset < end {
+                    self.regs.neopixel_buf[offset..end].copy_from_slice(&pixels[..end - offset]);
+                }
+            }
+            [NEOPIXEL, 0x05] => {
+                self.regs.neopixel_shown = self.regs.neopixel_buf;
+                self.regs.neopixel_shows += 1;
+            }
+            _ => {}
+        }
+    }
+
+    fn read_register(&mut self, buf: &mut [u8]) {
+        buf.fill(0);
+        let mut respond = |bytes: &[u8]| {
+            let n = bytes.len().min(buf.len());
+            buf[..n].copy_from_slice(&bytes[..n]);
+        };
+
+        match self.regs.selected {
+            [STATUS, 0x01] => respond(&[self.hardware_id.into()]),
+            [STATUS, 0x02] => {
+                respond(&((self.product_id as u32) << 16 | self.date_code as u32).to_be_bytes())
+            }
+            [STATUS, 0x03] => respond(&self.options.to_be_bytes()),
+            [STATUS, 0x04] => respond(&self.temp.to_be_bytes()),
+            [GPIO, 0x04] => respond(&self.gpio_levels().to_be_bytes()),
+            [GPIO, 0x0A] => {
+                respond(&self.regs.gpio_int_flag.to_be_bytes());
+                self.regs.gpio_int_flag = 0;
+            }
+            [ADC, func] if func >= 0x07 => {
+                let channel = (func - 0x07) as usize;
+                respond(&self.adc.get(channel).copied().unwrap_or(0).to_be_bytes());
+            }
+            [EEPROM, offset] => {
+                let eeprom = &self.eeprom[..self.hardware_id.eeprom_size()];
+                respond(eeprom.get(offset as usize..).unwrap_or_default());
+            }
+            [TOUCH, func] if func >= 0x10 => {
+                let channel = (func - 0x10) as usize;
+                respond(&self.touch.get(channel).copied().unwrap_or(0).to_be_bytes());
+            }
+            [ENCODER, func] => {
+                let encoder = (func & 0x0F) as usize;
+                match func & 0xF0 {
+                    0x30 => respond(&self.regs.encoder_position[encoder].to_be_bytes()),
+                    0x40 => {
+                        respond(&self.regs.encoder_delta[encoder].to_be_bytes());
+                        self.regs.encoder_delta[encoder] = 0;
+                    }
+                    _ => {}
+                }
+            }
+            [KEYPAD, 0x04] => respond(&[self.regs.keypad_fifo_len as u8]),
+            [KEYPAD, 0x10] => {
+                let regs = &mut self.regs;
+                let n = regs.keypad_fifo_len.min(buf.len());
+                buf[..n].copy_from_slice(&regs.keypad_fifo[..n]);
+                regs.keypad_fifo.copy_within(n..regs.keypad_fifo_len, 0);
+                regs.keypad_fifo_len -= n;
+                self.scan_keypad();
+            }
+            _ => {}
+        }
+    }
+
+    fn process(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), MockError> {
+        if address != self.addr {
+            return Err(MockError::Nack(address));
+        }
+
+        let mut rx = [0u8; RX_BUF_SIZE];
+        let mut len = 0;
+        let mut writing = false;
+        for op in operations.iter_mut() {
+            match op {
+                Operation::Write(bytes) => {
+                    if len + bytes.len() > RX_BUF_SIZE {
+                        return Err(MockError::Overrun(len + bytes.len()));
+                    }
+                    rx[len..len + bytes.len()].copy_from_slice(bytes);
+                    len += bytes.len();
+                    writing = true;
+                }
+                Operation::Read(buf) => {
+                    if writing {
+                        self.end_write(&rx[..len]);
+                        len = 0;
+                        writing = false;
+                    }
+                    self.read_register(buf);
+                }
+            }
+        }
+        if writing {
+            self.end_write(&rx[..len]);
+        }
+        Ok(())
+    }
+
+    fn end_write(&mut self, bytes: &[u8]) {
+        if let [base, func, data @ ..] = bytes {
+            self.write_register([*base, *func], data);
+        }
+    }
+}
+
+/// The bit of a pin in the GPIO registers, or no bits for pins beyond them
+fn pin_bit(pin: u8) -> u32 {
+    1u32.checked_shl(pin.into()).unwrap_or(0)
+}
+
+/// The index of the key at `(x, y)` in the keypad's 8x8 grid
+fn key_index(x: u8, y: u8) -> Option<usize> {
+    (x < 8 && y < 8).then_some(((y << 3) | x) as usize)
+}
+
+impl ErrorType for SeesawEmulator {
+    type Error = MockError;
+}
+
+impl I2c for SeesawEmulator {
+    fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.process(address, operations)
+    }
+}
+
+impl DelayNs for SeesawEmulator {
+    fn delay_ns(&mut self, ns: u32) {
+        self.elapsed_ns += ns as u64;
+    }
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::i2c::I2c for SeesawEmulator {
+    async fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.process(address, operations)
+    }
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::delay::DelayNs for SeesawEmulator {
+    async fn delay_ns(&mut self, ns: u32) {
+        self.elapsed_ns += ns as u64;
+    }
+}
@@ -37,7 +37,21 @@ const WINTHRESH: &Reg = &[Modules::Adc.into_u8(), 0x05];
 
 /// RO - 16bits
 /// ADC value for channel 0
-const CHANNEL_0: &Reg = &[Modules::Adc.into_u8(), 0x07];
+pub(crate) const CHANNEL_0: &Reg = &[Modules::Adc.into_u8(), 0x07];
+
+/// Map a pin number to the ADC channel the firmware addresses it by
+pub(crate) const fn adc_channel(hardware_id: HardwareId, pin: u8) -> u8 {
+    match hardware_id {
+        HardwareId::SAMD09 => match pin {
+            2 => 0,
+            3 => 1,
+            4 => 2,
+            5 => 3,
+            _ => 0,
+        },
+        _ => pin,
+    }
+}
 
 /// The ADC provides the ability to measure analog voltages at 10-bit
 /// resolution. The SAMD09 seesaw has 4 ADC inputs, the Attiny8x7 has 11 ADC
@@ -60,17 +74,7 @@ pub trait AdcModule<D: Driver>: SeesawDevice<Driver = D> {
     /// silkscreen. On the default seesaw firmware on the SAMD09 breakout, pins
     /// 2, 3, and 4 are ADC-enabled.
     fn analog_read(&mut self, pin: u8) -> Result<u16, SeesawError<D::Error>> {
-        let pin_offset = match Self::HARDWARE_ID {
-            HardwareId::SAMD09 => match pin {
-                2 => 0,
-                3 => 1,
-                4 => 2,
-                5 => 3,
-                _ => 0,
-            },
-            _ => pin,
-        };
-
+        let pin_offset = adc_channel(Self::HARDWARE_ID, pin);
         let addr = self.addr();
         self.driver()
             .read_u16(addr, &[CHANNEL_0[0], CHANNEL_0[1] + pin_offset])
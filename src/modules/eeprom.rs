@@ -0,0 +1,133 @@
+use super::Reg;
+use crate::{
+    devices::SeesawDevice,
+    discovery::SEESAW_ADDR_RANGE,
+    modules::{status::StatusModule, Modules},
+    Driver, DriverExt, SeesawError,
+};
+
+/// The most data bytes the firmware accepts in a single write, after the two
+/// register bytes
+pub(crate) const WRITE_CHUNK: usize = 30;
+
+/// The most bytes the firmware returns from a single read
+pub(crate) const READ_CHUNK: usize = 32;
+
+/// RW - The EEPROM is addressed byte by byte, with the function register
+/// being the offset into it
+pub(crate) const fn eeprom_reg(offset: u8) -> Reg {
+    [Modules::Eeprom.into_u8(), offset]
+}
+
+/// Fails if an access of `len` bytes at `offset` doesn't fit in an EEPROM of
+/// `size` bytes
+pub(crate) fn check_bounds<E>(offset: u8, len: usize, size: usize) -> Result<(), SeesawError<E>> {
+    let end = offset as usize + len;
+    match end <= size {
+        true => Ok(()),
+        false => Err(SeesawError::EepromOutOfBounds { end, size }),
+    }
+}
+
+/// Fails if `addr` isn't an address a seesaw can be moved to
+pub(crate) fn check_addr<E>(addr: u8) -> Result<(), SeesawError<E>> {
+    match SEESAW_ADDR_RANGE.contains(&addr) {
+        true => Ok(()),
+        false => Err(SeesawError::InvalidAddress(addr)),
+    }
+}
+
+/// The EEPROM module provides a few bytes of non-volatile storage on the
+/// seesaw, e.g. for calibration data or board identifiers. Its size depends on
+/// the chip, see
+/// [`HardwareId::eeprom_size`](crate::modules::HardwareId::eeprom_size).
+///
+/// The last byte of the EEPROM is reserved by the firmware for the device's
+/// I2C address. It can be read like any other byte, but is only written by
+/// [`change_addr`](EepromModule::change_addr).
+///
+/// The module base register address for the EEPROM module is 0x0D.
+pub trait EepromModule<D: Driver>: SeesawDevice<Driver = D> {
+    /// The size of the device's EEPROM in bytes
+    const EEPROM_SIZE: usize = Self::HARDWARE_ID.eeprom_size();
+
+    /// Persistently change the device's I2C address
+    ///
+    /// The new address is written to the last byte of the EEPROM and the
+    /// device is reset, after which it is verified to respond at `addr` with
+    /// its expected hardware ID.
+    ///
+    /// Once the address has been written the device is rebound to `addr`, even
+    /// if the reset or the verification fails, since that's where it will
+    /// answer from its next reset on. The old address can be restored with
+    /// [`set_addr`](SeesawDevice::set_addr).
+    ///
+    /// The address survives power cycles, and takes precedence over the
+    /// address jumpers on the board until the EEPROM byte is erased.
+    fn change_addr(&mut self, addr: u8) -> Result<(), SeesawError<D::Error>>
+    where
+        Self: Sized,
+    {
+        check_addr(addr)?;
+        let reg = eeprom_reg((Self::EEPROM_SIZE - 1) as u8);
+        let old_addr = self.addr();
+        self.driver()
+            .register_write(old_addr, &reg, &[addr])
+            .map_err(SeesawError::I2c)?;
+        let reset = self.reset();
+        self.set_addr(addr);
+        reset?;
+        match self.hardware_id()? {
+            id if id == Self::HARDWARE_ID.into() => Ok(()),
+            id => Err(SeesawError::InvalidHardwareId(id)),
+        }
+    }
+
+    /// Read the byte at `offset`
+    fn eeprom_read(&mut self, offset: u8) -> Result<u8, SeesawError<D::Error>> {
+        check_bounds(offset, 1, Self::EEPROM_SIZE)?;
+        let addr = self.addr();
+        self.driver()
+            .read_u8(addr, &eeprom_reg(offset))
+            .map_err(SeesawError::I2c)
+    }
+
+    /// Fill `buf` with the bytes starting at `offset`
+    fn eeprom_read_slice(
+        &mut self,
+        offset: u8,
+        buf: &mut [u8],
+    ) -> Result<(), SeesawError<D::Error>> {
+        check_bounds(offset, buf.len(), Self::EEPROM_SIZE)?;
+        let addr = self.addr();
+        buf.chunks_mut(READ_CHUNK)
+            .enumerate()
+            .try_for_each(|(i, chunk)| {
+                let reg = eeprom_reg(offset + (i * READ_CHUNK) as u8);
+                self.driver().register_read_into(addr, &reg, chunk)
+            })
+            .map_err(SeesawError::I2c)
+    }
+
+    /// Write the byte at `offset`
+    fn eeprom_write(&mut self, offset: u8, value: u8) -> Result<(), SeesawError<D::Error>> {
+        self.eeprom_write_slice(offset, &[value])
+    }
+
+    /// Write `data` starting at `offset`
+    ///
+    /// The last byte of the EEPROM holds the device's I2C address, so writes
+    /// that would reach it fail with [`SeesawError::EepromOutOfBounds`]. Only
+    /// [`change_addr`](Self::change_addr) writes it.
+    fn eeprom_write_slice(&mut self, offset: u8, data: &[u8]) -> Result<(), SeesawError<D::Error>> {
+        check_bounds(offset, data.len(), Self::EEPROM_SIZE - 1)?;
+        let addr = self.addr();
+        data.chunks(WRITE_CHUNK)
+            .enumerate()
+            .try_for_each(|(i, chunk)| {
+                let reg = eeprom_reg(offset + (i * WRITE_CHUNK) as u8);
+                self.driver().register_write(addr, &reg, chunk)
+            })
+            .map_err(SeesawError::I2c)
+    }
+}
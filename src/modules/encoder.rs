@@ -1,15 +1,15 @@
 use super::{
-    gpio::{GpioModule, PinMode},
+    gpio::{pin_mask, Button, GpioModule, PinMode},
     Modules, Reg,
 };
 use crate::{Driver, DriverExt, SeesawError};
 
 #[allow(dead_code)]
 const STATUS: &Reg = &[Modules::Encoder.into_u8(), 0x00];
-const INT_SET: &Reg = &[Modules::Encoder.into_u8(), 0x10];
-const INT_CLR: &Reg = &[Modules::Encoder.into_u8(), 0x20];
-const POSITION: &Reg = &[Modules::Encoder.into_u8(), 0x30];
-const DELTA: &Reg = &[Modules::Encoder.into_u8(), 0x40];
+pub(crate) const INT_SET: &Reg = &[Modules::Encoder.into_u8(), 0x10];
+pub(crate) const INT_CLR: &Reg = &[Modules::Encoder.into_u8(), 0x20];
+pub(crate) const POSITION: &Reg = &[Modules::Encoder.into_u8(), 0x30];
+pub(crate) const DELTA: &Reg = &[Modules::Encoder.into_u8(), 0x40];
 
 pub trait EncoderModule<D: Driver, const N_ENCODERS: usize>: GpioModule<D> {
     const ENCODER_BTN_PINS: [u8; N_ENCODERS];
@@ -19,8 +19,20 @@ pub trait EncoderModule<D: Driver, const N_ENCODERS: usize>: GpioModule<D> {
             .map(|_| self.driver().delay_us(125))
     }
 
+    /// Same as [`enable_button`](Self::enable_button), for every encoder in a
+    /// single transaction
+    fn enable_buttons(&mut self) -> Result<(), SeesawError<D::Error>> {
+        self.set_pin_mode_bulk(pin_mask(&Self::ENCODER_BTN_PINS), PinMode::InputPullup)
+            .map(|_| self.driver().delay_us(125))
+    }
+
     fn button(&mut self, encoder: usize) -> Result<bool, SeesawError<D::Error>> {
-        self.digital_read(Self::ENCODER_BTN_PINS[encoder])
+        self.read_button(Button::active_low(Self::ENCODER_BTN_PINS[encoder]))
+    }
+
+    /// Whether each encoder's button is pressed, read in a single transaction
+    fn buttons(&mut self) -> Result<[bool; N_ENCODERS], SeesawError<D::Error>> {
+        self.read_buttons(&Self::ENCODER_BTN_PINS.map(Button::active_low))
     }
 
     fn delta(&mut self, encoder: usize) -> Result<i32, SeesawError<D::Error>> {
@@ -4,56 +4,51 @@ use crate::{devices::SeesawDevice, Driver, DriverExt, SeesawError};
 /// WO - 32 bits
 /// Writing a 1 to any bit in this register sets the direction of the
 /// corresponding pin to OUTPUT. Writing 0 has no effect.
-#[allow(dead_code)]
-const SET_OUTPUT: &Reg = &[Modules::Gpio.into_u8(), 0x02];
+pub(crate) const SET_OUTPUT: &Reg = &[Modules::Gpio.into_u8(), 0x02];
 
 /// WO - 32 bits
 /// Writing a 1 to any bit in this register sets the direction of the
 /// corresponding pin to INPUT. Writing 0 has no effect.
-const SET_INPUT: &Reg = &[Modules::Gpio.into_u8(), 0x03];
+pub(crate) const SET_INPUT: &Reg = &[Modules::Gpio.into_u8(), 0x03];
 
 /// WR - 32 bits
 /// When written to, all bits that are set to 0 will have their
 /// corresponding pins set LOW. All bits that are set to 1 will
 /// have their corresponding pins set HIGH.
 /// Reading this register reads all pins on PORTA of the seesaw device.
-const GPIO: &Reg = &[Modules::Gpio.into_u8(), 0x04];
+pub(crate) const GPIO: &Reg = &[Modules::Gpio.into_u8(), 0x04];
 
 /// WO - 32 bits
 /// Writing a 1 to any bit in this register writes the corresponding pin
 /// HIGH. Writing 0 has no effect.
-const SET_HIGH: &Reg = &[Modules::Gpio.into_u8(), 0x05];
+pub(crate) const SET_HIGH: &Reg = &[Modules::Gpio.into_u8(), 0x05];
 
 /// WO - 32 bits
 /// Writing a 1 to any bit in this register writes the corresponding pin
 /// LOW. Writing 0 has no effect.
-const SET_LOW: &Reg = &[Modules::Gpio.into_u8(), 0x06];
+pub(crate) const SET_LOW: &Reg = &[Modules::Gpio.into_u8(), 0x06];
 
 /// W0 - 32 bits
 /// Writing a 1 to any bit in this register toggles the corresponding pin.
 /// Writing 0 has no effect.
-#[allow(dead_code)]
-const TOGGLE: &Reg = &[Modules::Gpio.into_u8(), 0x07];
+pub(crate) const TOGGLE: &Reg = &[Modules::Gpio.into_u8(), 0x07];
 
 /// WO - 32 bits
 /// Writing a 1 to any bit in this register enables the interrupt on the
 /// corresponding pin. When the value on this pin changes, the corresponding
 /// bit will be set in the INTFLAG register. Writing 0 has no effect.
-#[allow(dead_code)]
-const INT_ENABLE: &Reg = &[Modules::Gpio.into_u8(), 0x08];
+pub(crate) const INT_ENABLE: &Reg = &[Modules::Gpio.into_u8(), 0x08];
 
 /// WO - 32 bits
 /// Writing a 1 to any bit in this register disables the interrupt on the
 /// corresponding pin. Writing 0 has no effect.
-#[allow(dead_code)]
-const INT_DISABLE: &Reg = &[Modules::Gpio.into_u8(), 0x09];
+pub(crate) const INT_DISABLE: &Reg = &[Modules::Gpio.into_u8(), 0x09];
 
 /// RO - 32 bits
 /// This register hold the status of all GPIO interrupts.
 /// When an interrupt fires, the corresponding bit in this register gets
 /// set. Reading this register clears all interrupts.
-#[allow(dead_code)]
-const INT_FLAG: &Reg = &[Modules::Gpio.into_u8(), 0x0A];
+pub(crate) const INT_FLAG: &Reg = &[Modules::Gpio.into_u8(), 0x0A];
 
 /// WO - 32 bits
 /// Writing a 1 to any bit in this register enables the internal pullup or
@@ -61,13 +56,12 @@ const INT_FLAG: &Reg = &[Modules::Gpio.into_u8(), 0x0A];
 /// determined by the GPIO (output) value - if the corresponding GPIO
 /// register bit is low,  its a pulldown. High, its a pullup. Writing 0 has
 /// no effect.
-const PULL_ENABLE: &Reg = &[Modules::Gpio.into_u8(), 0x0B];
+pub(crate) const PULL_ENABLE: &Reg = &[Modules::Gpio.into_u8(), 0x0B];
 
 /// WO - 32 bits
 /// Writing a 1 to any bit in this register disables the pull up/down on the
 /// corresponding pin. Writing 0 has no effect.
-#[allow(dead_code)]
-const PULL_DISABLE: &Reg = &[Modules::Gpio.into_u8(), 0x0C];
+pub(crate) const PULL_DISABLE: &Reg = &[Modules::Gpio.into_u8(), 0x0C];
 
 /// The GPIO module provides every day input and outputs. You'll get logic GPIO
 /// pins that can act as outputs or inputs. With pullups or pulldowns. When
@@ -81,9 +75,9 @@ const PULL_DISABLE: &Reg = &[Modules::Gpio.into_u8(), 0x0C];
 ///
 /// The module base register address for the GPIO module is 0x01.
 pub trait GpioModule<D: Driver>: SeesawDevice<Driver = D> {
+    /// The level of a pin, `true` when it's high
     fn digital_read(&mut self, pin: u8) -> Result<bool, SeesawError<D::Error>> {
-        self.digital_read_bulk()
-            .map(|pins| !matches!((pins >> pin) & 0x1, 1))
+        self.digital_read_bulk().map(|pins| (pins >> pin) & 1 == 1)
     }
 
     fn digital_read_bulk(&mut self) -> Result<u32, SeesawError<D::Error>> {
@@ -91,6 +85,21 @@ pub trait GpioModule<D: Driver>: SeesawDevice<Driver = D> {
         self.driver().read_u32(addr, GPIO).map_err(SeesawError::I2c)
     }
 
+    /// Whether a button is pressed, going by its polarity
+    fn read_button(&mut self, button: Button) -> Result<bool, SeesawError<D::Error>> {
+        self.digital_read_bulk().map(|pins| button.is_pressed(pins))
+    }
+
+    /// Whether each of several buttons is pressed, read in a single
+    /// transaction
+    fn read_buttons<const N: usize>(
+        &mut self,
+        buttons: &[Button; N],
+    ) -> Result<[bool; N], SeesawError<D::Error>> {
+        self.digital_read_bulk()
+            .map(|pins| buttons.map(|button| button.is_pressed(pins)))
+    }
+
     fn digital_write(&mut self, pin: u8, output: PinOutput) -> Result<(), SeesawError<D::Error>> {
         self.digital_write_bulk(1 << pin, output)
     }
@@ -112,10 +121,69 @@ pub trait GpioModule<D: Driver>: SeesawDevice<Driver = D> {
         .map_err(SeesawError::I2c)
     }
 
+    /// Disable the pin-change interrupt on a pin
+    fn disable_pin_interrupt(&mut self, pin: u8) -> Result<(), SeesawError<D::Error>> {
+        self.disable_pin_interrupt_bulk(1 << pin)
+    }
+
+    /// Disable the pin-change interrupts on every pin set in `pins`
+    fn disable_pin_interrupt_bulk(&mut self, pins: u32) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+        self.driver()
+            .write_u32(addr, INT_DISABLE, pins)
+            .map_err(SeesawError::I2c)
+    }
+
+    /// Disable the pull-up or pull-down resistor on a pin
+    fn disable_pull(&mut self, pin: u8) -> Result<(), SeesawError<D::Error>> {
+        self.disable_pull_bulk(1 << pin)
+    }
+
+    /// Disable the pull-up or pull-down resistors on every pin set in `pins`
+    fn disable_pull_bulk(&mut self, pins: u32) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+        self.driver()
+            .write_u32(addr, PULL_DISABLE, pins)
+            .map_err(SeesawError::I2c)
+    }
+
+    /// Enable the pin-change interrupt on a pin. Whenever the pin's value
+    /// changes, its bit is set in the interrupt flags and the IRQ pin is
+    /// asserted until the flags are read.
+    fn enable_pin_interrupt(&mut self, pin: u8) -> Result<(), SeesawError<D::Error>> {
+        self.enable_pin_interrupt_bulk(1 << pin)
+    }
+
+    /// Enable the pin-change interrupts on every pin set in `pins`
+    fn enable_pin_interrupt_bulk(&mut self, pins: u32) -> Result<(), SeesawError<D::Error>> {
+        let addr = self.addr();
+        self.driver()
+            .write_u32(addr, INT_ENABLE, pins)
+            .map_err(SeesawError::I2c)
+    }
+
+    /// Read the interrupt flags of all pins. A set bit means the
+    /// corresponding pin has changed since the flags were last read.
+    ///
+    /// Reading the flags clears them and releases the IRQ pin.
+    fn read_pin_interrupt_flags(&mut self) -> Result<u32, SeesawError<D::Error>> {
+        let addr = self.addr();
+        self.driver()
+            .read_u32(addr, INT_FLAG)
+            .map_err(SeesawError::I2c)
+    }
+
     fn set_pin_mode(&mut self, pin: u8, mode: PinMode) -> Result<(), SeesawError<D::Error>> {
         self.set_pin_mode_bulk(1 << pin, mode)
     }
 
+    /// Set the mode of every pin set in `pins`
+    ///
+    /// `Pullup` and `Pulldown` enable the pull resistor without changing the
+    /// pins' direction. The seesaw firmware has no way to configure open-drain
+    /// outputs or pin function muxing, so `OpenDrain`, `OutputOpenDrain`,
+    /// `Special`, `Analog` and the `Function` modes fail with
+    /// [`SeesawError::UnsupportedPinMode`].
     fn set_pin_mode_bulk(&mut self, pins: u32, mode: PinMode) -> Result<(), SeesawError<D::Error>> {
         let addr = self.addr();
         let bus = self.driver();
@@ -123,6 +191,12 @@ pub trait GpioModule<D: Driver>: SeesawDevice<Driver = D> {
         match mode {
             PinMode::Output => bus.write_u32(addr, SET_OUTPUT, pins),
             PinMode::Input => bus.write_u32(addr, SET_INPUT, pins),
+            PinMode::Pullup => bus
+                .write_u32(addr, PULL_ENABLE, pins)
+                .and_then(|_| bus.write_u32(addr, SET_HIGH, pins)),
+            PinMode::Pulldown => bus
+                .write_u32(addr, PULL_ENABLE, pins)
+                .and_then(|_| bus.write_u32(addr, SET_LOW, pins)),
             PinMode::InputPullup => bus
                 .write_u32(addr, SET_INPUT, pins)
                 .and_then(|_| bus.write_u32(addr, PULL_ENABLE, pins))
@@ -131,12 +205,95 @@ pub trait GpioModule<D: Driver>: SeesawDevice<Driver = D> {
                 .write_u32(addr, SET_INPUT, pins)
                 .and_then(|_| bus.write_u32(addr, PULL_ENABLE, pins))
                 .and_then(|_| bus.write_u32(addr, SET_LOW, pins)),
-            _ => unimplemented!("Other pins modes are not supported."),
+            PinMode::OpenDrain
+            | PinMode::OutputOpenDrain
+            | PinMode::Special
+            | PinMode::Function1
+            | PinMode::Function2
+            | PinMode::Function3
+            | PinMode::Function4
+            | PinMode::Function5
+            | PinMode::Function6
+            | PinMode::Analog => return Err(SeesawError::UnsupportedPinMode(mode.into())),
         }
         .map_err(SeesawError::I2c)
     }
 }
 
+/// The bitmask of `pins`, for the bulk GPIO registers
+#[allow(dead_code)]
+pub(crate) const fn pin_mask(pins: &[u8]) -> u32 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < pins.len() {
+        mask |= 1 << pins[i];
+        i += 1;
+    }
+    mask
+}
+
+/// The level that makes a pin active
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Polarity {
+    /// Active when high, like a button pulled down that connects to power
+    ActiveHigh,
+    /// Active when low, like a button pulled up that connects to ground
+    ActiveLow,
+}
+
+impl Polarity {
+    /// Whether a pin at a level, `true` when high, is active
+    pub const fn is_active(self, level: bool) -> bool {
+        match self {
+            Polarity::ActiveHigh => level,
+            Polarity::ActiveLow => !level,
+        }
+    }
+}
+
+/// A button or switch on a pin, and the level it reads when pressed
+///
+/// The buttons of the built-in devices are pulled up, and so active low.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Button {
+    pub pin: u8,
+    pub polarity: Polarity,
+}
+
+impl Button {
+    /// A button that reads high when pressed
+    pub const fn active_high(pin: u8) -> Self {
+        Self {
+            pin,
+            polarity: Polarity::ActiveHigh,
+        }
+    }
+
+    /// A button that reads low when pressed
+    pub const fn active_low(pin: u8) -> Self {
+        Self {
+            pin,
+            polarity: Polarity::ActiveLow,
+        }
+    }
+
+    /// The mode that pulls the pin to its inactive level while the button
+    /// isn't pressed
+    pub const fn pin_mode(self) -> PinMode {
+        match self.polarity {
+            Polarity::ActiveHigh => PinMode::InputPulldown,
+            Polarity::ActiveLow => PinMode::InputPullup,
+        }
+    }
+
+    /// Whether the button is pressed in a bulk read of the pins
+    pub const fn is_pressed(self, pins: u32) -> bool {
+        self.polarity.is_active((pins >> self.pin) & 1 == 1)
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
@@ -175,8 +332,14 @@ impl From<PinMode> for u8 {
     }
 }
 
+/// The trigger modes of the SAMD pin interrupt controller
+///
+/// The seesaw firmware doesn't expose these: its GPIO interrupts are always
+/// pin-change interrupts, enabled per pin with
+/// [`enable_pin_interrupt`](GpioModule::enable_pin_interrupt).
+#[deprecated(note = "the seesaw firmware only supports pin-change interrupts; use \
+                     `GpioModule::enable_pin_interrupt`")]
 #[derive(Clone, Copy, Debug)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum InterruptMode {
     Disabled = 0x00,
@@ -189,8 +352,17 @@ pub enum InterruptMode {
     OnhighWe = 0x0D,
 }
 
+#[allow(deprecated)]
 impl From<InterruptMode> for u8 {
     fn from(value: InterruptMode) -> Self {
         value as u8
     }
 }
+
+#[cfg(feature = "defmt")]
+#[allow(deprecated)]
+impl defmt::Format for InterruptMode {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{}", defmt::Debug2Format(self))
+    }
+}
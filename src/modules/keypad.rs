@@ -1,3 +1,4 @@
+pub use crate::buttons::{KeyEvent, KeyEventType};
 use crate::{
     devices::SeesawDevice,
     driver::Driver,
@@ -7,11 +8,17 @@ use crate::{
 
 #[allow(dead_code)]
 const STATUS: &Reg = &[Modules::Keypad.into_u8(), 0x00];
-const EVENT: &Reg = &[Modules::Keypad.into_u8(), 0x01];
-const INT_SET: &Reg = &[Modules::Keypad.into_u8(), 0x02];
-const INT_CLR: &Reg = &[Modules::Keypad.into_u8(), 0x03];
-const COUNT: &Reg = &[Modules::Keypad.into_u8(), 0x04];
-const FIFO: &Reg = &[Modules::Keypad.into_u8(), 0x10];
+pub(crate) const EVENT: &Reg = &[Modules::Keypad.into_u8(), 0x01];
+pub(crate) const INT_SET: &Reg = &[Modules::Keypad.into_u8(), 0x02];
+pub(crate) const INT_CLR: &Reg = &[Modules::Keypad.into_u8(), 0x03];
+pub(crate) const COUNT: &Reg = &[Modules::Keypad.into_u8(), 0x04];
+pub(crate) const FIFO: &Reg = &[Modules::Keypad.into_u8(), 0x10];
+
+/// The number of events the firmware's key event FIFO holds
+pub(crate) const FIFO_SIZE: usize = 32;
+
+/// The most times `drain_key_events` empties the FIFO in a single call
+pub(crate) const DRAIN_ROUNDS: usize = 4;
 
 pub trait KeypadModule<D: Driver>: SeesawDevice<Driver = D> {
     const NUM_COLS: u8;
@@ -39,24 +46,75 @@ pub trait KeypadModule<D: Driver>: SeesawDevice<Driver = D> {
             .map_err(SeesawError::I2c)
     }
 
+    /// Read the key events waiting in the FIFO, until it's empty
+    ///
+    /// The returned iterator holds as many events as the FIFO, so a single call
+    /// takes every event that was waiting. If more arrive while reading than
+    /// fit, the rest are left for the next call.
     fn read_key_events(&mut self) -> Result<KeyEventIter, SeesawError<D::Error>> {
         let addr = self.addr();
-        let n = self
-            .driver()
-            .read_u8(addr, COUNT)
-            .map_err(SeesawError::I2c)?;
-        if n == 0 {
-            return Ok(KeyEventIter::default());
+        let mut events = KeyEventIter::default();
+        let mut raw = [0; FIFO_SIZE];
+
+        loop {
+            let n = self
+                .driver()
+                .read_u8(addr, COUNT)
+                .map_err(SeesawError::I2c)? as usize;
+            events.overflowed |= n >= FIFO_SIZE;
+            let n = n.min(FIFO_SIZE - events.len);
+            if n == 0 {
+                return Ok(events);
+            }
+            self.driver()
+                .register_read_into(addr, FIFO, &mut raw[..n])
+                .map_err(SeesawError::I2c)?;
+            events.extend_raw(&raw[..n]);
         }
-        let events = self
-            .driver()
-            .register_read::<16>(addr, FIFO)
-            .map_err(SeesawError::I2c)?;
-        let mut buf: [Option<KeyEvent>; 16] = [None; 16];
-        for i in 0..n as usize {
-            buf[i] = Some(events[i].into());
+    }
+
+    /// Read key events until the FIFO is empty, passing each to `f`
+    ///
+    /// Unlike [`read_key_events`](Self::read_key_events), the FIFO is emptied
+    /// several times over, so bursts that outlast a single read arrive intact,
+    /// e.g. into a `heapless::Vec`:
+    ///
+    /// ```rs
+    /// let mut events = heapless::Vec::<KeyEvent, 128>::new();
+    /// let drain = trellis.drain_key_events(|e| events.push(e).unwrap())?;
+    /// ```
+    ///
+    /// Keys with the `IsPressed` or `IsReleased` triggers enabled refill the
+    /// FIFO as fast as it's read, so a call stops after emptying it 4 times,
+    /// at most 128 events, and sets
+    /// [`more_pending`](KeyEventDrain::more_pending) if events are still
+    /// waiting.
+    fn drain_key_events<F: FnMut(KeyEvent)>(
+        &mut self,
+        mut f: F,
+    ) -> Result<KeyEventDrain, SeesawError<D::Error>> {
+        let addr = self.addr();
+        let mut drain = KeyEventDrain::default();
+        let mut raw = [0; FIFO_SIZE];
+
+        for round in 0..=DRAIN_ROUNDS {
+            let n = self
+                .driver()
+                .read_u8(addr, COUNT)
+                .map_err(SeesawError::I2c)? as usize;
+            drain.overflowed |= n >= FIFO_SIZE;
+            let n = n.min(FIFO_SIZE);
+            drain.more_pending = n > 0;
+            if n == 0 || round == DRAIN_ROUNDS {
+                break;
+            }
+            self.driver()
+                .register_read_into(addr, FIFO, &mut raw[..n])
+                .map_err(SeesawError::I2c)?;
+            raw[..n].iter().for_each(|&e| f(e.into()));
+            drain.count += n;
         }
-        Ok(KeyEventIter { buf, curr: 0 })
+        Ok(drain)
     }
 
     fn set_key_event_triggers(
@@ -82,8 +140,25 @@ pub trait KeypadModule<D: Driver>: SeesawDevice<Driver = D> {
 #[derive(Debug, Default, Clone, Copy)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct KeyEventIter {
-    buf: [Option<KeyEvent>; 16],
-    curr: usize,
+    pub(crate) buf: [Option<KeyEvent>; FIFO_SIZE],
+    pub(crate) curr: usize,
+    pub(crate) len: usize,
+    pub(crate) overflowed: bool,
+}
+
+impl KeyEventIter {
+    /// Whether the FIFO was full when it was read, in which case the firmware
+    /// may have dropped events
+    pub fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+
+    pub(crate) fn extend_raw(&mut self, raw: &[u8]) {
+        raw.iter().for_each(|&e| {
+            self.buf[self.len] = Some(e.into());
+            self.len += 1;
+        });
+    }
 }
 
 impl Iterator for KeyEventIter {
@@ -99,39 +174,16 @@ impl Iterator for KeyEventIter {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[cfg_attr(feature = "defmt", derive(defmt::Format))]
-#[repr(u8)]
-pub enum KeyEventType {
-    /// steady-state key is down
-    IsPressed = 0,
-    /// steady-state key is up
-    IsReleased = 1,
-    /// one-shot as key is released
-    Released = 2,
-    /// one-shot as key is pressed
-    Pressed = 3,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The outcome of [`KeypadModule::drain_key_events`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-pub struct KeyEvent {
-    pub event: KeyEventType,
-    pub x: u8,
-    pub y: u8,
-}
-
-impl From<u8> for KeyEvent {
-    fn from(value: u8) -> Self {
-        let event = match value & 3 {
-            0 => KeyEventType::IsPressed,
-            1 => KeyEventType::IsReleased,
-            2 => KeyEventType::Released,
-            3 => KeyEventType::Pressed,
-            _ => unreachable!(),
-        };
-        let x = (value >> 2) & 7;
-        let y = (value >> 2) >> 3;
-        Self { event, x, y }
-    }
+pub struct KeyEventDrain {
+    /// The number of events delivered
+    pub count: usize,
+    /// Whether events were still waiting when the call stopped reading, in
+    /// which case it should be called again
+    pub more_pending: bool,
+    /// Whether the FIFO was full when it was read, in which case the firmware
+    /// may have dropped events
+    pub overflowed: bool,
 }
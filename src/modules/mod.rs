@@ -1,5 +1,7 @@
 #[cfg(feature = "module_adc")]
 pub mod adc;
+#[cfg(feature = "module_eeprom")]
+pub mod eeprom;
 #[cfg(feature = "module_encoder")]
 pub mod encoder;
 #[cfg(feature = "module_gpio")]
@@ -11,6 +13,8 @@ pub mod neopixel;
 pub mod status;
 #[cfg(feature = "module_timer")]
 pub mod timer;
+#[cfg(feature = "module_touch")]
+pub mod touch;
 
 pub type Reg = [u8; 2];
 
@@ -33,12 +37,45 @@ pub enum HardwareId {
     ATTINY1617 = 0x89,
 }
 
+impl HardwareId {
+    /// The size of the EEPROM available to the seesaw firmware in bytes. On
+    /// the SAMD09 it is emulated in flash.
+    pub const fn eeprom_size(self) -> usize {
+        match self {
+            HardwareId::SAMD09 => 64,
+            HardwareId::ATTINY806
+            | HardwareId::ATTINY807
+            | HardwareId::ATTINY816
+            | HardwareId::ATTINY817 => 128,
+            HardwareId::ATTINY1616 | HardwareId::ATTINY1617 => 256,
+        }
+    }
+}
+
 impl From<HardwareId> for u8 {
     fn from(value: HardwareId) -> Self {
         value as u8
     }
 }
 
+/// Fails with the unrecognized ID
+impl TryFrom<u8> for HardwareId {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x55 => Ok(HardwareId::SAMD09),
+            0x84 => Ok(HardwareId::ATTINY806),
+            0x85 => Ok(HardwareId::ATTINY807),
+            0x86 => Ok(HardwareId::ATTINY816),
+            0x87 => Ok(HardwareId::ATTINY817),
+            0x88 => Ok(HardwareId::ATTINY1616),
+            0x89 => Ok(HardwareId::ATTINY1617),
+            id => Err(id),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub(crate) enum Modules {
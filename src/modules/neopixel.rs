@@ -1,56 +1,69 @@
 use super::{Modules, Reg};
 use crate::{devices::SeesawDevice, driver::Driver, DriverExt, SeesawError};
-use rgb::ComponentSlice;
+use rgb::{Grb, RGB8, RGBA8};
 
 /// WO - 8 bits
 /// This register sets the pin number (PORTA) that is used for the NeoPixel
 /// output.
-const SET_PIN: &Reg = &[Modules::Neopixel.into_u8(), 0x01];
+pub(crate) const SET_PIN: &Reg = &[Modules::Neopixel.into_u8(), 0x01];
 /// WO - 8 bits
 /// The protocol speed. (see `NeopixelSpeed`) Default is 800khz.
-const SET_SPEED: &Reg = &[Modules::Neopixel.into_u8(), 0x02];
+pub(crate) const SET_SPEED: &Reg = &[Modules::Neopixel.into_u8(), 0x02];
 /// WO - 16 bits
 /// The number of bytes currently used for the pixel array. This is
 /// dependent on when the pixels you are using are RGB or RGBW.
-const SET_LEN: &Reg = &[Modules::Neopixel.into_u8(), 0x03];
+pub(crate) const SET_LEN: &Reg = &[Modules::Neopixel.into_u8(), 0x03];
 /// WO - 256 bits (32 bytes)
 /// The data buffer. The first 2 bytes are the start address, and the data
 /// to write follows. Data should be written in blocks of maximum size 30
 /// bytes at a time.
-const SET_BUF: &Reg = &[Modules::Neopixel.into_u8(), 0x04];
+pub(crate) const SET_BUF: &Reg = &[Modules::Neopixel.into_u8(), 0x04];
 /// W0 - Zero bits
 /// Sending the SHOW command will cause the output to update. There's no
 /// arguments/data after the command.
-const SHOW: &Reg = &[Modules::Neopixel.into_u8(), 0x05];
+pub(crate) const SHOW: &Reg = &[Modules::Neopixel.into_u8(), 0x05];
 
 pub trait NeopixelModule<D: Driver>: SeesawDevice<Driver = D> {
-    /// The size of the color type in bytes
-    const C_SIZE: usize = {
-        match core::mem::size_of::<Self::Color>() {
-            3 => 3,
-            4 => 4,
-            _ => panic!("Invalid color size"),
-        }
-    };
-    /// The number of neopixels on or connected to the device
+    /// The order in which the neopixels expect the color components of each
+    /// pixel
+    const COLOR_ORDER: ColorOrder = ColorOrder::Grb;
+    /// The size of each pixel in bytes
+    const C_SIZE: usize = Self::COLOR_ORDER.bytes_per_pixel();
+    /// The number of neopixels on or connected to the device, or the most it
+    /// can drive if the length of its strip is set at runtime
     const N_LEDS: usize = 1;
     /// The output pin of the neopixel signal
     const PIN: u8;
 
-    type Color: ComponentSlice<u8>;
+    /// The number of neopixels the device drives. Defaults to `N_LEDS`;
+    /// devices whose strip is configured at runtime override this and the
+    /// other `neopixel_` getters.
+    fn neopixel_len(&self) -> usize {
+        Self::N_LEDS
+    }
+
+    /// The order in which the neopixels expect the color components of each
+    /// pixel. Defaults to `COLOR_ORDER`.
+    fn neopixel_color_order(&self) -> ColorOrder {
+        Self::COLOR_ORDER
+    }
+
+    /// The output pin of the neopixel signal. Defaults to `PIN`.
+    fn neopixel_pin(&self) -> u8 {
+        Self::PIN
+    }
 
     /// Set which pin the device sends the neopixel signal through and
     /// set the length of its internal pixel buffer
     fn enable_neopixel(&mut self) -> Result<(), SeesawError<D::Error>> {
         let addr = self.addr();
+        let pin = self.neopixel_pin();
+        let len = self.neopixel_len() * self.neopixel_color_order().bytes_per_pixel();
 
         self.driver()
-            .write_u8(addr, SET_PIN, Self::PIN)
+            .write_u8(addr, SET_PIN, pin)
             .map(|_| self.driver().delay_us(10_000))
-            .and_then(|_| {
-                self.driver()
-                    .write_u16(addr, SET_LEN, (Self::C_SIZE * Self::N_LEDS) as u16)
-            })
+            .and_then(|_| self.driver().write_u16(addr, SET_LEN, len as u16))
             .map(|_| self.driver().delay_us(10_000))
             .map_err(SeesawError::I2c)
     }
@@ -73,67 +86,59 @@ pub trait NeopixelModule<D: Driver>: SeesawDevice<Driver = D> {
 
     /// Set the color of the first (and, in the case of some devices, only)
     /// neopixel
-    fn set_neopixel_color(&mut self, color: Self::Color) -> Result<(), SeesawError<D::Error>>
-    where
-        [(); 2 + Self::C_SIZE]: Sized,
-    {
+    fn set_neopixel_color<C: NeopixelColor>(
+        &mut self,
+        color: C,
+    ) -> Result<(), SeesawError<D::Error>> {
         self.set_nth_neopixel_color(0, color)
     }
 
     /// Set the color of the nth neopixel
-    fn set_nth_neopixel_color(
+    fn set_nth_neopixel_color<C: NeopixelColor>(
         &mut self,
         n: usize,
-        color: Self::Color,
-    ) -> Result<(), SeesawError<D::Error>>
-    where
-        [(); 2 + Self::C_SIZE]: Sized,
-    {
-        assert!(n < Self::N_LEDS);
-        let addr = self.addr();
-        let mut buf = [0; 2 + Self::C_SIZE];
-        buf[..2].copy_from_slice(&u16::to_be_bytes((Self::C_SIZE * n) as u16));
-        buf[2..].copy_from_slice(color.as_slice());
-        self.driver()
-            .register_write(addr, SET_BUF, &buf)
-            .map_err(SeesawError::I2c)
+        color: C,
+    ) -> Result<(), SeesawError<D::Error>> {
+        self.set_neopixel_colors_from(n, &[color])
     }
 
-    /// Set the color of all neopixels
+    /// Set the colors of the neopixels, starting from the first. `colors` may
+    /// be shorter than the strip, in which case the remaining neopixels are
+    /// left unchanged.
     ///
-    /// Minimizes the number of transactions performed by chunking the `colors`
-    /// array into the largest (max 30 byte) buffers possible
+    /// Minimizes the number of transactions performed by chunking `colors`
+    /// into the largest (max 30 byte) buffers possible
+    fn set_neopixel_colors<C: NeopixelColor>(
+        &mut self,
+        colors: &[C],
+    ) -> Result<(), SeesawError<D::Error>> {
+        self.set_neopixel_colors_from(0, colors)
+    }
+
+    /// Set the colors of the neopixels, starting from the `first`, chunked like
+    /// [`set_neopixel_colors`](Self::set_neopixel_colors)
     ///
-    /// Note that if `C_SIZE` is _not_ 3 or 4 bytes, the chunking optimization
-    /// is effectively skipped
-    fn set_neopixel_colors(
+    /// Fails with [`SeesawError::NeopixelOutOfBounds`], without writing any
+    /// pixels, if `colors` runs past the end of the strip.
+    fn set_neopixel_colors_from<C: NeopixelColor>(
         &mut self,
-        colors: &[Self::Color; Self::N_LEDS],
-    ) -> Result<(), SeesawError<D::Error>>
-    where
-        [(); 2 + color_bytes_per_write(Self::C_SIZE)]: Sized,
-    {
-        let mut buf = [0; 2 + color_bytes_per_write(Self::C_SIZE)];
+        first: usize,
+        colors: &[C],
+    ) -> Result<(), SeesawError<D::Error>> {
+        check_pixels(first, colors.len(), self.neopixel_len())?;
         let addr = self.addr();
+        let order = self.neopixel_color_order();
+        let c_size = order.bytes_per_pixel();
+        let per_write = max_colors_per_write(c_size);
+        let mut buf = [0; 32];
 
         colors
-            .chunks(max_colors_per_write(Self::C_SIZE))
+            .chunks(per_write)
             .enumerate()
             .try_for_each(|(i, chunk)| {
-                let offset = u16::to_be_bytes(
-                    (Self::C_SIZE * i * max_colors_per_write(Self::C_SIZE)) as u16,
-                );
-                buf[..2].copy_from_slice(&offset);
-                chunk.iter().enumerate().for_each(|(j, c)| {
-                    let start = 2 + (j * Self::C_SIZE);
-                    buf[start..start + Self::C_SIZE].copy_from_slice(c.as_slice());
-                });
-
-                self.driver().register_write(
-                    addr,
-                    SET_BUF,
-                    &buf[0..2 + (Self::C_SIZE * chunk.len())],
-                )
+                let offset = first * c_size + i * color_bytes_per_write(c_size);
+                let len = encode_colors(&mut buf, order, offset, chunk);
+                self.driver().register_write(addr, SET_BUF, &buf[..len])
             })
             .map_err(SeesawError::I2c)
     }
@@ -148,14 +153,43 @@ pub trait NeopixelModule<D: Driver>: SeesawDevice<Driver = D> {
     }
 }
 
+/// Fails if writing `count` pixels from `first` runs past the end of a strip
+/// of `len` pixels
+pub(crate) fn check_pixels<E>(
+    first: usize,
+    count: usize,
+    len: usize,
+) -> Result<(), SeesawError<E>> {
+    let end = first + count;
+    match end <= len {
+        true => Ok(()),
+        false => Err(SeesawError::NeopixelOutOfBounds { end, len }),
+    }
+}
+
+/// Fill `buf` with a `SET_BUF` payload writing `colors` in `order` at the byte
+/// `offset` of the device's pixel buffer, returning the length of the payload
+pub(crate) fn encode_colors<C: NeopixelColor>(
+    buf: &mut [u8; 32],
+    order: ColorOrder,
+    offset: usize,
+    colors: &[C],
+) -> usize {
+    let c_size = order.bytes_per_pixel();
+    buf[..2].copy_from_slice(&(offset as u16).to_be_bytes());
+    colors.iter().enumerate().for_each(|(i, &color)| {
+        order.encode(color, &mut buf[2 + i * c_size..]);
+    });
+    2 + c_size * colors.len()
+}
+
+/// The maximum number of color bytes the device accepts in a single write
+const MAX_COLOR_BYTES_PER_WRITE: usize = 30;
+
 /// Get the maximum number of colors that can be written in a single write
 /// operation as a function of the number of bytes per color
 pub const fn max_colors_per_write(c_size: usize) -> usize {
-    match c_size {
-        3 => 9, // 27
-        4 => 7, // 28
-        _ => 1, // effectively skips the optimization
-    }
+    MAX_COLOR_BYTES_PER_WRITE / c_size
 }
 
 /// Get the number of bytes dedicated to writing colors in a single write
@@ -164,6 +198,74 @@ pub const fn color_bytes_per_write(c_size: usize) -> usize {
     c_size * max_colors_per_write(c_size)
 }
 
+/// A color that can be written to neopixels in any [`ColorOrder`]
+///
+/// `RGBA8`'s alpha channel is used as the white channel of RGBW neopixels.
+/// Colors without a white channel leave it off, and the white channel is
+/// dropped when writing to RGB neopixels.
+pub trait NeopixelColor: Copy {
+    /// The red, green, blue and white components of the color
+    fn to_rgbw(self) -> RGBA8;
+}
+
+impl NeopixelColor for RGB8 {
+    fn to_rgbw(self) -> RGBA8 {
+        RGBA8::new(self.r, self.g, self.b, 0)
+    }
+}
+
+impl NeopixelColor for RGBA8 {
+    fn to_rgbw(self) -> RGBA8 {
+        self
+    }
+}
+
+impl NeopixelColor for Grb<u8> {
+    fn to_rgbw(self) -> RGBA8 {
+        RGBA8::new(self.r, self.g, self.b, 0)
+    }
+}
+
+/// The order in which neopixels expect the color components of each pixel
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ColorOrder {
+    Rgb,
+    Rbg,
+    #[default]
+    Grb,
+    Gbr,
+    Brg,
+    Bgr,
+    Rgbw,
+    Grbw,
+}
+
+impl ColorOrder {
+    /// The number of bytes each pixel takes up in the device's buffer
+    pub const fn bytes_per_pixel(self) -> usize {
+        match self {
+            ColorOrder::Rgbw | ColorOrder::Grbw => 4,
+            _ => 3,
+        }
+    }
+
+    /// Write `color` to the start of `buf` in this order
+    pub fn encode<C: NeopixelColor>(self, color: C, buf: &mut [u8]) {
+        let RGBA8 { r, g, b, a: w } = color.to_rgbw();
+        match self {
+            ColorOrder::Rgb => buf[..3].copy_from_slice(&[r, g, b]),
+            ColorOrder::Rbg => buf[..3].copy_from_slice(&[r, b, g]),
+            ColorOrder::Grb => buf[..3].copy_from_slice(&[g, r, b]),
+            ColorOrder::Gbr => buf[..3].copy_from_slice(&[g, b, r]),
+            ColorOrder::Brg => buf[..3].copy_from_slice(&[b, r, g]),
+            ColorOrder::Bgr => buf[..3].copy_from_slice(&[b, g, r]),
+            ColorOrder::Rgbw => buf[..4].copy_from_slice(&[r, g, b, w]),
+            ColorOrder::Grbw => buf[..4].copy_from_slice(&[g, r, b, w]),
+        }
+    }
+}
+
 /// NeopixelModule: The Neopixel protocol speed
 #[derive(Debug, Default)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -1,11 +1,11 @@
 use super::{Modules, Reg};
 use crate::{devices::SeesawDevice, driver::Driver, DriverExt, SeesawError};
 
-const STATUS_HW_ID: &Reg = &[Modules::Status.into_u8(), 0x01];
-const STATUS_VERSION: &Reg = &[Modules::Status.into_u8(), 0x02];
-const STATUS_OPTIONS: &Reg = &[Modules::Status.into_u8(), 0x03];
-const STATUS_TEMP: &Reg = &[Modules::Status.into_u8(), 0x04];
-const STATUS_SWRST: &Reg = &[Modules::Status.into_u8(), 0x7F];
+pub(crate) const STATUS_HW_ID: &Reg = &[Modules::Status.into_u8(), 0x01];
+pub(crate) const STATUS_VERSION: &Reg = &[Modules::Status.into_u8(), 0x02];
+pub(crate) const STATUS_OPTIONS: &Reg = &[Modules::Status.into_u8(), 0x03];
+pub(crate) const STATUS_TEMP: &Reg = &[Modules::Status.into_u8(), 0x04];
+pub(crate) const STATUS_SWRST: &Reg = &[Modules::Status.into_u8(), 0x7F];
 
 pub trait StatusModule<D: Driver>: SeesawDevice<Driver = D> {
     /// Returns the available capabilities compiled into the seesaw firmware
@@ -107,7 +107,7 @@ impl From<u32> for DeviceCapabilities {
 }
 
 /// StatusModule
-#[derive(Debug)]
+#[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct ProductDateCode {
     pub id: u16,
@@ -1,12 +1,32 @@
 use super::{Modules, Reg};
 use crate::{devices::SeesawDevice, modules::HardwareId, Driver, DriverExt, SeesawError};
 
-/// WO - 16 bits
+/// WO - 16 or 24 bits
 /// The first byte of the register indicates which PWM pin will have its value
-/// set The second byte is the actual PWM value
-const PWM_VAL: &Reg = &[Modules::Timer.into_u8(), 0x01];
+/// set. The remaining one or two bytes are the actual PWM value, 8 or 16 bits
+/// wide respectively.
+pub(crate) const PWM_VAL: &Reg = &[Modules::Timer.into_u8(), 0x01];
 
-/// The PWM module provides up to 4 8-bit PWM outputs.
+/// WO - 24 bits
+/// The first byte of the register indicates which PWM pin will have its
+/// frequency set. The second and third bytes are the frequency in Hz.
+pub(crate) const FREQ: &Reg = &[Modules::Timer.into_u8(), 0x02];
+
+/// Map a pin number to the PWM output the firmware addresses it by
+pub(crate) const fn pwm_output(hardware_id: HardwareId, pin: u8) -> u8 {
+    match hardware_id {
+        HardwareId::SAMD09 => match pin {
+            4 => 0,
+            5 => 1,
+            6 => 2,
+            7 => 3,
+            _ => 0,
+        },
+        _ => pin,
+    }
+}
+
+/// The PWM module provides up to 4 PWM outputs with 16-bit resolution.
 /// The module base register address for the PWM module is 0x08.
 /// PWM outputs are available on pins PA04, PA05, PA06, and PA07.
 pub trait TimerModule<D: Driver>: SeesawDevice<Driver = D> {
@@ -16,20 +36,36 @@ pub trait TimerModule<D: Driver>: SeesawDevice<Driver = D> {
     /// silkscreen. On the default seesaw firmware on the SAMD09 breakout,
     /// pins 5, 6, and 7 are PWM enabled.
     fn analog_write(&mut self, pin: u8, value: u8) -> Result<(), SeesawError<D::Error>> {
-        let mapped_pin = match Self::HARDWARE_ID {
-            HardwareId::SAMD09 => match pin {
-                4 => 0,
-                5 => 1,
-                6 => 2,
-                7 => 3,
-                _ => 0,
-            },
-            _ => pin,
-        };
+        let mapped_pin = pwm_output(Self::HARDWARE_ID, pin);
 
         let addr = self.addr();
         self.driver()
             .write_u16(addr, PWM_VAL, u16::from_be_bytes([mapped_pin, value]))
             .map_err(SeesawError::I2c)
     }
+
+    /// Write a 16-bit PWM value to a PWM-enabled pin
+    fn analog_write_u16(&mut self, pin: u8, value: u16) -> Result<(), SeesawError<D::Error>> {
+        let [hi, lo] = value.to_be_bytes();
+        let mapped_pin = pwm_output(Self::HARDWARE_ID, pin);
+
+        let addr = self.addr();
+        self.driver()
+            .register_write(addr, PWM_VAL, &[mapped_pin, hi, lo])
+            .map_err(SeesawError::I2c)
+    }
+
+    /// Set the frequency of a PWM-enabled pin in Hz
+    ///
+    /// Pins driven by the same timer share a frequency, so changing the
+    /// frequency of one pin may change it for others too.
+    fn set_pwm_freq(&mut self, pin: u8, freq: u16) -> Result<(), SeesawError<D::Error>> {
+        let [hi, lo] = freq.to_be_bytes();
+        let mapped_pin = pwm_output(Self::HARDWARE_ID, pin);
+
+        let addr = self.addr();
+        self.driver()
+            .register_write(addr, FREQ, &[mapped_pin, hi, lo])
+            .map_err(SeesawError::I2c)
+    }
 }
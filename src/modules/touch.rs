@@ -0,0 +1,75 @@
+use super::{HardwareId, Modules, Reg};
+use crate::{devices::SeesawDevice, Driver, SeesawError};
+
+/// RO - 16 bits
+/// Capacitive reading of touch channel 0. The registers of the other channels
+/// follow consecutively.
+pub(crate) const CHANNEL_0: &Reg = &[Modules::Touch.into_u8(), 0x10];
+
+/// The number of times a touch reading is attempted before giving up
+pub(crate) const READ_ATTEMPTS: u32 = 5;
+
+/// How long to wait for a touch reading on the first attempt, in
+/// microseconds. Each retry waits another millisecond longer.
+pub(crate) const READ_DELAY_US: u32 = 3_000;
+
+/// Map a pin number to the touch channel the firmware addresses it by. On
+/// the SAMD09, pins 2 to 5 are touch channels 0 to 3 and every other pin reads
+/// channel 0, like `analog_read`.
+pub(crate) const fn touch_channel(hardware_id: HardwareId, pin: u8) -> u8 {
+    match hardware_id {
+        HardwareId::SAMD09 => match pin {
+            2 => 0,
+            3 => 1,
+            4 => 2,
+            5 => 3,
+            _ => 0,
+        },
+        _ => pin,
+    }
+}
+
+/// The register holding the reading of touch channel `channel`
+pub(crate) const fn channel_reg(channel: u8) -> Reg {
+    [CHANNEL_0[0], CHANNEL_0[1] + channel]
+}
+
+/// The touch module measures the capacitance of the pads or probes connected
+/// to its channels, e.g. the probe of the STEMMA soil sensor or the Crickit's
+/// touch pads.
+///
+/// The module base register address for the touch module is 0x0F.
+///
+/// The firmware takes a few milliseconds to sample a channel, so readings
+/// wait longer than other registers and are retried if the device doesn't
+/// respond in time.
+pub trait TouchModule<D: Driver>: SeesawDevice<Driver = D> {
+    /// Read the raw capacitive value of a touch-enabled pin. Higher values
+    /// mean more capacitance.
+    ///
+    /// On the SAMD09, the pin corresponds to the number on the silkscreen, and
+    /// pins 2, 3, 4 and 5 are touch channels 0 to 3. Other pins read channel 0,
+    /// e.g. the soil sensor's probe is read from pin 0. On the ATtiny8x7, the
+    /// pin is the touch channel.
+    fn touch_read(&mut self, pin: u8) -> Result<u16, SeesawError<D::Error>> {
+        let addr = self.addr();
+        let reg = channel_reg(touch_channel(Self::HARDWARE_ID, pin));
+        let bus = self.driver();
+        let mut buf = [0u8; 2];
+        let mut result = Ok(());
+
+        for attempt in 0..READ_ATTEMPTS {
+            result = bus.write(addr, &reg).and_then(|_| {
+                bus.delay_us(READ_DELAY_US + attempt * 1_000);
+                bus.read(addr, &mut buf)
+            });
+            if result.is_ok() {
+                break;
+            }
+        }
+
+        result
+            .map(|_| u16::from_be_bytes(buf))
+            .map_err(SeesawError::I2c)
+    }
+}
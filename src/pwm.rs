@@ -0,0 +1,66 @@
+//! [`embedded-hal` PWM](embedded_hal::pwm) for individual seesaw PWM outputs.
+//!
+//! Like [`GpioPin`](crate::digital::GpioPin), a [`PwmPin`] borrows its device
+//! from a [`RefCell`], so several pins of the same device can be driven
+//! independently:
+//!
+//! ```rs
+//! let device = RefCell::new(ArcadeButton1x4::new_with_default_addr(seesaw).init()?);
+//! let mut led = PwmPin::new(&device, 12);
+//! led.set_duty_cycle_percent(25)?;
+//! ```
+use crate::{modules::timer::TimerModule, Driver, SeesawError};
+use core::{cell::RefCell, fmt::Debug, marker::PhantomData};
+use embedded_hal::pwm::{ErrorKind, ErrorType, SetDutyCycle};
+
+impl<E: Debug> embedded_hal::pwm::Error for SeesawError<E> {
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Other
+    }
+}
+
+/// A single PWM output of a seesaw device, with 16-bit duty cycle resolution
+#[derive(Debug)]
+pub struct PwmPin<'a, D, T> {
+    device: &'a RefCell<T>,
+    pin: u8,
+    _driver: PhantomData<D>,
+}
+
+impl<'a, D: Driver, T: TimerModule<D>> PwmPin<'a, D, T> {
+    /// Drive `pin` of `device`, which must be PWM-enabled
+    pub fn new(device: &'a RefCell<T>, pin: u8) -> Self {
+        Self {
+            device,
+            pin,
+            _driver: PhantomData,
+        }
+    }
+
+    /// The pin number
+    pub fn pin(&self) -> u8 {
+        self.pin
+    }
+
+    /// Set the PWM frequency in Hz
+    ///
+    /// Pins driven by the same timer share a frequency, so changing the
+    /// frequency of one pin may change it for others too.
+    pub fn set_frequency(&mut self, freq: u16) -> Result<(), SeesawError<D::Error>> {
+        self.device.borrow_mut().set_pwm_freq(self.pin, freq)
+    }
+}
+
+impl<D: Driver, T: TimerModule<D>> ErrorType for PwmPin<'_, D, T> {
+    type Error = SeesawError<D::Error>;
+}
+
+impl<D: Driver, T: TimerModule<D>> SetDutyCycle for PwmPin<'_, D, T> {
+    fn max_duty_cycle(&self) -> u16 {
+        u16::MAX
+    }
+
+    fn set_duty_cycle(&mut self, duty: u16) -> Result<(), Self::Error> {
+        self.device.borrow_mut().analog_write_u16(self.pin, duty)
+    }
+}
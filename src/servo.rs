@@ -0,0 +1,97 @@
+//! Hobby servos driven from a seesaw PWM output.
+//!
+//! ```rs
+//! let device = RefCell::new(GenericDevice::new(0x49, seesaw));
+//! let mut servo = Servo::new(&device, 0)?.with_pulse_range(600, 2400);
+//! servo.set_angle(90.0)?;
+//! ```
+use crate::{modules::timer::TimerModule, pwm::PwmPin, Driver, SeesawError};
+use core::cell::RefCell;
+use embedded_hal::pwm::SetDutyCycle;
+
+/// The PWM frequency servos expect, in Hz
+pub const SERVO_FREQ: u16 = 50;
+
+/// The length of one PWM period at [`SERVO_FREQ`], in microseconds
+const PERIOD_US: u32 = 1_000_000 / SERVO_FREQ as u32;
+
+/// A servo attached to a PWM-enabled pin
+///
+/// Servos are positioned by the width of the pulse they receive every 20ms.
+/// By default the pulse is between 750µs and 2250µs over a 180° range, but
+/// servos vary, so calibrate with
+/// [`with_pulse_range`](Self::with_pulse_range) and
+/// [`with_actuation_range`](Self::with_actuation_range).
+#[derive(Debug)]
+pub struct Servo<'a, D, T> {
+    pwm: PwmPin<'a, D, T>,
+    min_pulse_us: u16,
+    max_pulse_us: u16,
+    actuation_range: f32,
+}
+
+impl<'a, D: Driver, T: TimerModule<D>> Servo<'a, D, T> {
+    /// Drive a servo from `pin` of `device`, setting the pin's PWM frequency
+    /// to [`SERVO_FREQ`]
+    pub fn new(device: &'a RefCell<T>, pin: u8) -> Result<Self, SeesawError<D::Error>> {
+        let mut pwm = PwmPin::new(device, pin);
+        pwm.set_frequency(SERVO_FREQ)?;
+        Ok(Self {
+            pwm,
+            min_pulse_us: 750,
+            max_pulse_us: 2250,
+            actuation_range: 180.0,
+        })
+    }
+
+    /// Set the angle the servo travels between its minimum and maximum pulse
+    /// widths, in degrees
+    pub fn with_actuation_range(mut self, degrees: f32) -> Self {
+        self.actuation_range = degrees;
+        self
+    }
+
+    /// Set the pulse widths, in microseconds, corresponding to an angle of 0
+    /// and the end of the actuation range, or to full reverse and full
+    /// forward throttle
+    pub fn with_pulse_range(mut self, min_us: u16, max_us: u16) -> Self {
+        self.min_pulse_us = min_us;
+        self.max_pulse_us = max_us;
+        self
+    }
+
+    /// The PWM pin the servo is driven from
+    pub fn pwm(&mut self) -> &mut PwmPin<'a, D, T> {
+        &mut self.pwm
+    }
+
+    /// Stop sending pulses, letting the servo go limp
+    pub fn relax(&mut self) -> Result<(), SeesawError<D::Error>> {
+        self.pwm.set_duty_cycle_fully_off()
+    }
+
+    /// Move to `degrees`, which is clamped to the actuation range
+    pub fn set_angle(&mut self, degrees: f32) -> Result<(), SeesawError<D::Error>> {
+        self.set_fraction(degrees / self.actuation_range)
+    }
+
+    /// Send pulses `us` microseconds wide, regardless of the calibrated pulse
+    /// range
+    pub fn set_pulse_width_us(&mut self, us: u16) -> Result<(), SeesawError<D::Error>> {
+        let duty = (us as u32 * u16::MAX as u32 / PERIOD_US).min(u16::MAX as u32);
+        self.pwm.set_duty_cycle(duty as u16)
+    }
+
+    /// Set the speed of a continuous rotation servo, from -1.0 (full reverse)
+    /// through 0.0 (stopped) to 1.0 (full forward). Values outside that range
+    /// are clamped.
+    pub fn set_throttle(&mut self, throttle: f32) -> Result<(), SeesawError<D::Error>> {
+        self.set_fraction((throttle + 1.0) / 2.0)
+    }
+
+    fn set_fraction(&mut self, fraction: f32) -> Result<(), SeesawError<D::Error>> {
+        let span = self.max_pulse_us as f32 - self.min_pulse_us as f32;
+        let us = self.min_pulse_us as f32 + span * fraction.clamp(0.0, 1.0);
+        self.set_pulse_width_us(us as u16)
+    }
+}
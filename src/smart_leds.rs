@@ -0,0 +1,134 @@
+//! [`smart-leds`](https://docs.rs/smart-leds) support for seesaw neopixels.
+//!
+//! Wrapping a [`NeopixelModule`] device in a [`SmartLedsAdapter`] lets it be
+//! driven by anything written against `SmartLedsWrite`, including the
+//! `smart-leds` `brightness` and `gamma` iterators:
+//!
+//! ```rs
+//! let mut leds: SmartLedsAdapter<_, _> = SmartLedsAdapter::new(neokeys);
+//! leds.write(brightness(gamma(colors.iter().cloned()), 32))?;
+//! ```
+#[cfg(feature = "async")]
+use crate::asynch::{AsyncDriver, AsyncNeopixelModule};
+use crate::{
+    modules::neopixel::{max_colors_per_write, NeopixelColor, NeopixelModule},
+    Driver, SeesawError,
+};
+use core::marker::PhantomData;
+use rgb::{RGB8, RGBA8};
+#[cfg(feature = "async")]
+use smart_leds_trait::SmartLedsWriteAsync;
+use smart_leds_trait::{SmartLedsWrite, White, RGBW};
+
+/// The most colors written in a single transaction, for 3 byte colors
+const MAX_CHUNK: usize = max_colors_per_write(3);
+
+/// Drives the neopixels of a device as a `smart-leds` target, taking colors
+/// of type `C`
+///
+/// Each write sends colors in chunks as large as a transaction allows, then
+/// shows them. Colors beyond the end of the device's strip are
+/// ignored.
+///
+/// `C` defaults to `RGB8`. RGBW devices can take `smart-leds`' `RGBW<u8>`
+/// instead.
+#[derive(Debug)]
+pub struct SmartLedsAdapter<D, T, C = RGB8> {
+    device: T,
+    _color: PhantomData<(D, C)>,
+}
+
+impl<D, T, C> SmartLedsAdapter<D, T, C> {
+    pub fn new(device: T) -> Self {
+        Self {
+            device,
+            _color: PhantomData,
+        }
+    }
+
+    /// The device being written to
+    pub fn device(&mut self) -> &mut T {
+        &mut self.device
+    }
+
+    /// Give back the device
+    pub fn release(self) -> T {
+        self.device
+    }
+}
+
+impl<D: Driver, T: NeopixelModule<D>, C: NeopixelColor> SmartLedsWrite
+    for SmartLedsAdapter<D, T, C>
+{
+    type Color = C;
+    type Error = SeesawError<D::Error>;
+
+    fn write<It, I>(&mut self, iterator: It) -> Result<(), Self::Error>
+    where
+        It: IntoIterator<Item = I>,
+        I: Into<Self::Color>,
+    {
+        let per_write = max_colors_per_write(self.device.neopixel_color_order().bytes_per_pixel());
+        let mut buf = [RGBA8::default(); MAX_CHUNK];
+        let mut first = 0;
+        let mut len = 0;
+
+        for color in iterator.into_iter().take(self.device.neopixel_len()) {
+            buf[len] = color.into().to_rgbw();
+            len += 1;
+            if len == per_write {
+                self.device.set_neopixel_colors_from(first, &buf[..len])?;
+                first += len;
+                len = 0;
+            }
+        }
+        if len > 0 {
+            self.device.set_neopixel_colors_from(first, &buf[..len])?;
+        }
+        self.device.sync_neopixel()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<D: AsyncDriver, T: AsyncNeopixelModule<D>, C: NeopixelColor> SmartLedsWriteAsync
+    for SmartLedsAdapter<D, T, C>
+{
+    type Color = C;
+    type Error = SeesawError<D::Error>;
+
+    async fn write<It, I>(&mut self, iterator: It) -> Result<(), Self::Error>
+    where
+        It: IntoIterator<Item = I>,
+        I: Into<Self::Color>,
+    {
+        let per_write = max_colors_per_write(self.device.neopixel_color_order().bytes_per_pixel());
+        let mut buf = [RGBA8::default(); MAX_CHUNK];
+        let mut first = 0;
+        let mut len = 0;
+
+        for color in iterator.into_iter().take(self.device.neopixel_len()) {
+            buf[len] = color.into().to_rgbw();
+            len += 1;
+            if len == per_write {
+                self.device
+                    .set_neopixel_colors_from(first, &buf[..len])
+                    .await?;
+                first += len;
+                len = 0;
+            }
+        }
+        if len > 0 {
+            self.device
+                .set_neopixel_colors_from(first, &buf[..len])
+                .await?;
+        }
+        self.device.sync_neopixel().await
+    }
+}
+
+impl NeopixelColor for RGBW<u8> {
+    fn to_rgbw(self) -> RGBA8 {
+        let White(w) = self.a;
+        RGBA8::new(self.r, self.g, self.b, w)
+    }
+}
@@ -0,0 +1,35 @@
+#![cfg(all(feature = "mock", feature = "async", feature = "device_neokey_1x4"))]
+use adafruit_seesaw::{asynch::prelude::*, devices::NeoKey1x4, mock::SeesawEmulator};
+use std::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+/// Run a future that never waits, as the emulator's never do
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    match future.as_mut().poll(&mut cx) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("emulated futures should complete immediately"),
+    }
+}
+
+#[test]
+fn inits_with_interrupts() {
+    let emulator = SeesawEmulator::for_device::<NeoKey1x4<()>>();
+    let mut device = block_on(AsyncButtonInterrupts::init_with_interrupts(
+        NeoKey1x4::new_with_default_addr(emulator),
+    ))
+    .unwrap();
+    (4..8).for_each(|pin| assert!(device.driver().is_pulled_up(pin)));
+    assert!(!device.driver().interrupt_pending());
+
+    device.driver().set_pin_level(6, false);
+    assert!(device.driver().interrupt_pending());
+    assert_eq!(
+        block_on(AsyncGpioModule::read_pin_interrupt_flags(&mut device)).unwrap(),
+        1 << 6
+    );
+}
@@ -0,0 +1,84 @@
+#![cfg(all(
+    feature = "mock",
+    any(feature = "module_adc", feature = "module_touch")
+))]
+use adafruit_seesaw::{mock::SeesawEmulator, prelude::*, seesaw_device, Driver};
+
+seesaw_device! {
+    name: Samd09,
+    hardware_id: HardwareId::SAMD09,
+    product_id: 0,
+    default_addr: 0x49
+}
+
+seesaw_device! {
+    name: Tiny,
+    hardware_id: HardwareId::ATTINY817,
+    product_id: 0,
+    default_addr: 0x49
+}
+
+#[cfg(feature = "module_adc")]
+impl<D: Driver> AdcModule<D> for Samd09<D> {}
+#[cfg(feature = "module_adc")]
+impl<D: Driver> AdcModule<D> for Tiny<D> {}
+#[cfg(feature = "module_touch")]
+impl<D: Driver> TouchModule<D> for Samd09<D> {}
+#[cfg(feature = "module_touch")]
+impl<D: Driver> TouchModule<D> for Tiny<D> {}
+
+/// An emulator whose ADC and touch channels 0 to 3 read 100 to 103
+fn emulator<T: SeesawDevice>() -> SeesawEmulator {
+    let mut emulator = SeesawEmulator::for_device::<T>();
+    (0..4).for_each(|channel| {
+        emulator.set_adc_channel(channel, 100 + channel as u16);
+        emulator.set_touch_channel(channel, 100 + channel as u16);
+    });
+    emulator
+}
+
+#[cfg(feature = "module_adc")]
+#[test]
+fn maps_samd09_pins_to_adc_channels() {
+    let mut device = Samd09::new_with_default_addr(emulator::<Samd09<()>>());
+    assert_eq!(device.analog_read(2).unwrap(), 100);
+    assert_eq!(device.analog_read(3).unwrap(), 101);
+    assert_eq!(device.analog_read(4).unwrap(), 102);
+    assert_eq!(device.analog_read(5).unwrap(), 103);
+}
+
+#[cfg(feature = "module_adc")]
+#[test]
+fn reads_attiny_pins_as_adc_channels() {
+    let mut device = Tiny::new_with_default_addr(emulator::<Tiny<()>>());
+    assert_eq!(device.analog_read(1).unwrap(), 101);
+    assert_eq!(device.analog_read(3).unwrap(), 103);
+}
+
+#[cfg(feature = "module_touch")]
+#[test]
+fn maps_samd09_pins_to_touch_channels() {
+    let mut device = Samd09::new_with_default_addr(emulator::<Samd09<()>>());
+    assert_eq!(device.touch_read(2).unwrap(), 100);
+    assert_eq!(device.touch_read(3).unwrap(), 101);
+    assert_eq!(device.touch_read(4).unwrap(), 102);
+    assert_eq!(device.touch_read(5).unwrap(), 103);
+    assert_eq!(device.touch_read(0).unwrap(), 100);
+}
+
+#[cfg(feature = "module_touch")]
+#[test]
+fn reads_attiny_pins_as_touch_channels() {
+    let mut device = Tiny::new_with_default_addr(emulator::<Tiny<()>>());
+    assert_eq!(device.touch_read(1).unwrap(), 101);
+    assert_eq!(device.touch_read(3).unwrap(), 103);
+}
+
+#[cfg(feature = "device_soil_sensor")]
+#[test]
+fn reads_soil_sensor_probe_from_touch_channel_0() {
+    use adafruit_seesaw::devices::SoilSensor;
+
+    let mut sensor = SoilSensor::new_with_default_addr(emulator::<SoilSensor<()>>());
+    assert_eq!(sensor.moisture().unwrap(), 100);
+}
@@ -0,0 +1,40 @@
+#![cfg(all(feature = "mock", feature = "device_neokey_1x4"))]
+use adafruit_seesaw::{
+    devices::NeoKey1x4,
+    digital::GpioPin,
+    mock::{Pull, SeesawEmulator},
+    prelude::*,
+};
+use core::cell::RefCell;
+use embedded_hal::digital::{InputPin, PinState};
+
+fn neokeys() -> RefCell<NeoKey1x4<SeesawEmulator>> {
+    let emulator = SeesawEmulator::for_device::<NeoKey1x4<SeesawEmulator>>();
+    RefCell::new(NeoKey1x4::new_with_default_addr(emulator).init().unwrap())
+}
+
+#[test]
+fn floating_input_disables_pull() {
+    let device = neokeys();
+    let pin = GpioPin::input_pullup(&device, 2).unwrap();
+    assert_eq!(device.borrow_mut().driver().pull(2), Some(Pull::Up));
+
+    let mut pin = pin.into_input().unwrap();
+    assert_eq!(device.borrow_mut().driver().pull(2), None);
+    assert!(!device.borrow_mut().driver().is_output(2));
+    assert!(pin.is_low().unwrap());
+
+    GpioPin::input(&device, 4).unwrap();
+    assert_eq!(device.borrow_mut().driver().pull(4), None);
+}
+
+#[test]
+fn output_drives_pin() {
+    let device = neokeys();
+    let pin = GpioPin::output(&device, 2, PinState::High).unwrap();
+    assert!(device.borrow_mut().driver().is_output(2));
+    assert!(device.borrow_mut().driver().pin_level(2));
+
+    pin.into_input_pulldown().unwrap();
+    assert_eq!(device.borrow_mut().driver().pull(2), Some(Pull::Down));
+}
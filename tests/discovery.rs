@@ -0,0 +1,78 @@
+#![cfg(feature = "mock")]
+use adafruit_seesaw::{
+    discovery::{scan, KnownDevice},
+    mock::SeesawEmulator,
+    modules::HardwareId,
+};
+
+fn identify(emulator: SeesawEmulator) -> Option<KnownDevice> {
+    let mut emulator = emulator.with_addr(0x49);
+    let report = scan(&mut emulator, 0x49..=0x49).next().unwrap().unwrap();
+    report.device
+}
+
+#[test]
+fn identifies_nothing_by_unknown_product_id() {
+    assert_eq!(
+        identify(SeesawEmulator::new(0x49, HardwareId::SAMD09, 1234)),
+        None
+    );
+}
+
+#[cfg(all(feature = "device_neokey_1x4", feature = "device_neorotary4"))]
+#[test]
+fn identifies_by_product_id() {
+    use adafruit_seesaw::devices::{NeoKey1x4, NeoRotary4};
+
+    assert_eq!(
+        identify(SeesawEmulator::for_device::<NeoKey1x4<()>>()),
+        Some(KnownDevice::NeoKey1x4)
+    );
+    assert_eq!(
+        identify(SeesawEmulator::for_device::<NeoRotary4<()>>()),
+        Some(KnownDevice::NeoRotary4)
+    );
+    assert_eq!(
+        identify(SeesawEmulator::new(0x49, HardwareId::SAMD09, 5752)),
+        None
+    );
+}
+
+#[cfg(feature = "device_neodriver")]
+#[test]
+fn identifies_neodriver_by_reported_product_id() {
+    assert_eq!(
+        identify(SeesawEmulator::new(0x49, HardwareId::ATTINY817, 5742)),
+        Some(KnownDevice::NeoDriver)
+    );
+    assert_eq!(
+        identify(SeesawEmulator::new(0x49, HardwareId::ATTINY817, 5766)),
+        Some(KnownDevice::NeoDriver)
+    );
+}
+
+#[cfg(feature = "device_neotrellis")]
+#[test]
+fn identifies_samd09_keypad_without_product_id_as_neotrellis() {
+    use adafruit_seesaw::devices::NeoTrellis;
+
+    /// `STATUS_OPTIONS` with only the status and GPIO modules
+    const NO_KEYPAD: u32 = 0b11;
+
+    assert_eq!(
+        identify(SeesawEmulator::for_device::<NeoTrellis<()>>()),
+        Some(KnownDevice::NeoTrellis)
+    );
+    assert_eq!(
+        identify(SeesawEmulator::new(0x49, HardwareId::SAMD09, 0)),
+        Some(KnownDevice::NeoTrellis)
+    );
+    assert_eq!(
+        identify(SeesawEmulator::new(0x49, HardwareId::SAMD09, 0).with_options(NO_KEYPAD)),
+        None
+    );
+    assert_eq!(
+        identify(SeesawEmulator::new(0x49, HardwareId::ATTINY817, 0)),
+        None
+    );
+}
@@ -0,0 +1,92 @@
+#![cfg(all(feature = "mock", feature = "module_eeprom"))]
+use adafruit_seesaw::{devices::GenericDevice, mock::SeesawEmulator, prelude::*, SeesawError};
+
+fn device() -> GenericDevice<SeesawEmulator> {
+    let emulator = SeesawEmulator::for_device::<GenericDevice<SeesawEmulator>>();
+    GenericDevice::new_with_default_addr(emulator)
+}
+
+#[test]
+fn reads_back_writes() {
+    let mut device = device();
+    let data: [u8; 40] = core::array::from_fn(|i| i as u8);
+    device.eeprom_write_slice(10, &data).unwrap();
+    assert_eq!(device.driver().eeprom()[10..50], data);
+
+    let mut buf = [0; 40];
+    device.eeprom_read_slice(10, &mut buf).unwrap();
+    assert_eq!(buf, data);
+    assert_eq!(device.eeprom_read(49).unwrap(), 39);
+}
+
+#[test]
+fn rejects_writes_to_address_byte() {
+    let mut device = device();
+    assert_eq!(GenericDevice::<SeesawEmulator>::EEPROM_SIZE, 64);
+    device.eeprom_write_slice(60, &[1, 2, 3]).unwrap();
+
+    assert!(matches!(
+        device.eeprom_write(63, 0x40),
+        Err(SeesawError::EepromOutOfBounds { end: 64, size: 63 })
+    ));
+    assert!(matches!(
+        device.eeprom_write_slice(60, &[1, 2, 3, 4]),
+        Err(SeesawError::EepromOutOfBounds { end: 64, size: 63 })
+    ));
+    assert_eq!(device.driver().eeprom()[60..], [1, 2, 3, 0xFF]);
+    assert_eq!(device.eeprom_read(63).unwrap(), 0xFF);
+}
+
+#[test]
+fn rejects_reads_past_end() {
+    let mut device = device();
+    let mut buf = [0; 2];
+    assert!(matches!(
+        device.eeprom_read_slice(63, &mut buf),
+        Err(SeesawError::EepromOutOfBounds { end: 65, size: 64 })
+    ));
+}
+
+#[test]
+fn changes_addr() {
+    let mut device = device();
+    device.change_addr(0x40).unwrap();
+    assert_eq!(device.addr(), 0x40);
+    assert_eq!(device.driver().addr(), 0x40);
+    assert_eq!(device.driver().eeprom()[63], 0x40);
+    assert_eq!(device.driver().resets(), 1);
+
+    assert!(matches!(
+        device.change_addr(0x78),
+        Err(SeesawError::InvalidAddress(0x78))
+    ));
+    assert_eq!(device.addr(), 0x40);
+}
+
+#[test]
+fn keeps_driver_when_device_doesnt_move() {
+    // The emulator's address lives in the last byte of its 256 byte EEPROM, so
+    // writing the 64th byte doesn't move it
+    let emulator = SeesawEmulator::new(0x49, HardwareId::ATTINY1616, 0);
+    let mut device = GenericDevice::new_with_default_addr(emulator);
+    assert!(device.change_addr(0x40).is_err());
+    assert_eq!(device.addr(), 0x40);
+
+    device.set_addr(0x49);
+    assert_eq!(device.hardware_id().unwrap(), HardwareId::ATTINY1616.into());
+}
+
+#[cfg(feature = "device_arcade_button_1x4")]
+#[test]
+fn verifies_hardware_id_at_new_addr() {
+    use adafruit_seesaw::devices::ArcadeButton1x4;
+
+    let emulator = SeesawEmulator::new(0x3A, HardwareId::ATTINY807, 0);
+    let mut device = ArcadeButton1x4::new_with_default_addr(emulator);
+    assert!(matches!(
+        device.change_addr(0x3B),
+        Err(SeesawError::InvalidHardwareId(0x85))
+    ));
+    assert_eq!(device.addr(), 0x3B);
+    assert_eq!(device.driver().addr(), 0x3B);
+}
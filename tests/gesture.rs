@@ -0,0 +1,85 @@
+use adafruit_seesaw::{
+    buttons::{KeyEvent, KeyEventType},
+    gesture::{Gesture, GestureConfig, GestureKind, GestureRecognizer},
+};
+use std::cell::Cell;
+
+const NO_DOUBLE_TAP: GestureConfig = GestureConfig {
+    double_tap_ms: 0,
+    long_press_ms: 500,
+    repeat_delay_ms: 500,
+    repeat_interval_ms: 0,
+};
+
+#[test]
+fn recognizes_taps_and_long_presses() {
+    let now = Cell::new(0);
+    let mut gestures = GestureRecognizer::<_, 4>::new(|| now.get(), 2).with_config(NO_DOUBLE_TAP);
+    let mut seen = Vec::new();
+
+    gestures.update(1, 1, true, |g| seen.push(g));
+    now.set(100);
+    gestures.update(1, 1, false, |g| seen.push(g));
+    gestures.update(0, 1, true, |g| seen.push(g));
+    now.set(700);
+    gestures.update(0, 1, false, |g| seen.push(g));
+
+    let gesture = |kind, x, y| Gesture { kind, x, y };
+    assert_eq!(
+        seen,
+        [
+            gesture(GestureKind::Tap, 1, 1),
+            gesture(GestureKind::Hold, 0, 1),
+            gesture(GestureKind::LongPress, 0, 1),
+        ]
+    );
+}
+
+#[test]
+fn ignores_keys_outside_grid() {
+    let now = Cell::new(0);
+    let mut gestures = GestureRecognizer::<_, 4>::new(|| now.get(), 2).with_config(NO_DOUBLE_TAP);
+    let mut seen = Vec::new();
+
+    gestures.set_key_config(7, 0, GestureConfig::default());
+    gestures.update(2, 0, true, |g| seen.push(g));
+    gestures.update(0, 2, true, |g| seen.push(g));
+    gestures.update(u8::MAX, u8::MAX, true, |g| seen.push(g));
+    (0..8).for_each(|x| {
+        let event = KeyEvent {
+            event: KeyEventType::Pressed,
+            x,
+            y: 7,
+        };
+        gestures.key_event(event, |g| seen.push(g));
+    });
+    assert_eq!(gestures.held_keys(), 0);
+    assert!(seen.is_empty());
+}
+
+#[test]
+fn tolerates_clock_going_backwards() {
+    let now = Cell::new(1_000);
+    let mut gestures = GestureRecognizer::<_, 4>::new(|| now.get(), 2).with_config(GestureConfig {
+        repeat_interval_ms: 100,
+        ..GestureConfig::default()
+    });
+    let mut seen = Vec::new();
+
+    gestures.update(0, 0, true, |g| seen.push(g));
+    now.set(0);
+    gestures.tick(|g| seen.push(g));
+    gestures.update(0, 0, false, |g| seen.push(g));
+    assert_eq!(seen, []);
+
+    now.set(u64::MAX);
+    gestures.tick(|g| seen.push(g));
+    assert_eq!(
+        seen,
+        [Gesture {
+            kind: GestureKind::Tap,
+            x: 0,
+            y: 0
+        }]
+    );
+}
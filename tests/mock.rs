@@ -0,0 +1,275 @@
+#![cfg(feature = "mock")]
+use adafruit_seesaw::{
+    devices::GenericDevice,
+    mock::{MockError, SeesawEmulator},
+    prelude::*,
+    SeesawError,
+};
+
+#[test]
+fn nacks_other_addresses() {
+    let emulator = SeesawEmulator::for_device::<GenericDevice<SeesawEmulator>>().with_addr(0x40);
+    let mut device = GenericDevice::new(0x41, emulator);
+    assert!(matches!(
+        device.hardware_id(),
+        Err(SeesawError::I2c(MockError::Nack(0x41)))
+    ));
+}
+
+#[test]
+fn init_resets_and_identifies() {
+    let emulator = SeesawEmulator::for_device::<GenericDevice<SeesawEmulator>>();
+    let mut device = GenericDevice::new_with_default_addr(emulator)
+        .init()
+        .unwrap();
+    assert_eq!(device.driver().resets(), 1);
+    assert_eq!(device.hardware_id().unwrap(), HardwareId::SAMD09.into());
+}
+
+#[test]
+fn out_of_range_helpers_are_ignored() {
+    let mut emulator = SeesawEmulator::for_device::<GenericDevice<SeesawEmulator>>();
+    emulator.set_pin_level(32, true);
+    emulator.set_pin_level(u8::MAX, false);
+    emulator.release_pin(40);
+    emulator.set_adc_channel(u8::MAX, 1);
+    emulator.set_touch_channel(u8::MAX, 1);
+    emulator.turn_encoder(usize::MAX, 1);
+    emulator.turn_encoder(0, i32::MAX);
+    emulator.turn_encoder(0, 1);
+
+    assert!(!emulator.pin_level(32));
+    assert!(!emulator.is_output(u8::MAX));
+    assert_eq!(emulator.pull(40), None);
+    assert!(!emulator.is_pin_interrupt_enabled(40));
+    assert_eq!(emulator.pwm(u8::MAX), 0);
+    assert_eq!(emulator.pwm_freq(u8::MAX), 0);
+    assert_eq!(emulator.encoder_position(usize::MAX), 0);
+    assert_eq!(emulator.encoder_position(0), i32::MIN);
+    assert!(!emulator.is_encoder_interrupt_enabled(usize::MAX));
+    assert_eq!(emulator.key_triggers(8, 0), 0);
+    assert_eq!(emulator.key_triggers(0, u8::MAX), 0);
+}
+
+#[cfg(feature = "device_neokey_1x4")]
+mod gpio {
+    use super::*;
+    use adafruit_seesaw::{devices::NeoKey1x4, mock::Pull};
+
+    fn neokeys() -> NeoKey1x4<SeesawEmulator> {
+        let emulator = SeesawEmulator::for_device::<NeoKey1x4<SeesawEmulator>>();
+        NeoKey1x4::new_with_default_addr(emulator).init().unwrap()
+    }
+
+    #[test]
+    fn sets_pin_direction() {
+        let mut device = neokeys();
+        assert!(!device.driver().is_output(2));
+
+        device.set_pin_mode(2, PinMode::Output).unwrap();
+        assert!(device.driver().is_output(2));
+        device.digital_write(2, PinOutput::High).unwrap();
+        assert!(device.driver().pin_level(2));
+        assert!(device.digital_read(2).unwrap());
+        device.digital_write(2, PinOutput::Toggle).unwrap();
+        assert!(!device.driver().pin_level(2));
+
+        device.set_pin_mode(2, PinMode::Input).unwrap();
+        assert!(!device.driver().is_output(2));
+    }
+
+    #[test]
+    fn sets_pulls() {
+        let mut device = neokeys();
+        device.set_pin_mode(2, PinMode::InputPullup).unwrap();
+        assert_eq!(device.driver().pull(2), Some(Pull::Up));
+        assert!(device.digital_read(2).unwrap());
+
+        device.set_pin_mode(2, PinMode::InputPulldown).unwrap();
+        assert_eq!(device.driver().pull(2), Some(Pull::Down));
+        assert!(!device.digital_read(2).unwrap());
+
+        device.disable_pull(2).unwrap();
+        assert_eq!(device.driver().pull(2), None);
+    }
+
+    #[test]
+    fn rejects_unsupported_pin_modes() {
+        let mut device = neokeys();
+        assert!(matches!(
+            device.set_pin_mode(2, PinMode::OpenDrain),
+            Err(SeesawError::UnsupportedPinMode(0x10))
+        ));
+    }
+
+    #[test]
+    fn reads_pulled_up_keys() {
+        let mut device = neokeys();
+        (4..8).for_each(|pin| assert!(device.driver().is_pulled_up(pin)));
+        assert_eq!(device.keys().unwrap(), 0b1111);
+
+        device.driver().set_pin_level(5, false);
+        assert_eq!(device.keys().unwrap(), 0b1101);
+        assert_eq!(device.key_values().unwrap(), [false, true, false, false]);
+
+        device.driver().release_pin(5);
+        assert_eq!(device.keys().unwrap(), 0b1111);
+    }
+
+    #[test]
+    fn latches_pin_interrupts() {
+        let mut device = neokeys().init_with_interrupts().unwrap();
+        assert!(!device.driver().interrupt_pending());
+
+        device.driver().set_pin_level(6, false);
+        assert!(device.driver().interrupt_pending());
+        assert_eq!(device.read_pin_interrupt_flags().unwrap(), 1 << 6);
+        assert!(!device.driver().interrupt_pending());
+    }
+
+    #[test]
+    fn writes_neopixel_buffer() {
+        let mut device = neokeys();
+        assert_eq!(device.driver().neopixel_pin(), Some(3));
+        assert_eq!(device.driver().neopixel_len(), 12);
+
+        let colors = [
+            rgb::RGB8::new(1, 2, 3),
+            rgb::RGB8::new(4, 5, 6),
+            rgb::RGB8::new(7, 8, 9),
+        ];
+        device.set_neopixel_colors_from(1, &colors).unwrap();
+        assert_eq!(
+            device.driver().neopixel_buffer(),
+            [0, 0, 0, 2, 1, 3, 5, 4, 6, 8, 7, 9]
+        );
+        assert_eq!(device.driver().neopixel_shown(), [0; 12]);
+
+        device.sync_neopixel().unwrap();
+        assert_eq!(device.driver().neopixel_shows(), 1);
+        assert_eq!(
+            device.driver().neopixel_shown(),
+            [0, 0, 0, 2, 1, 3, 5, 4, 6, 8, 7, 9]
+        );
+    }
+}
+
+#[cfg(feature = "device_neotrellis")]
+mod keypad {
+    use super::*;
+    use adafruit_seesaw::devices::NeoTrellis;
+
+    fn trellis() -> NeoTrellis<SeesawEmulator> {
+        let emulator = SeesawEmulator::for_device::<NeoTrellis<SeesawEmulator>>();
+        NeoTrellis::new_with_default_addr(emulator).init().unwrap()
+    }
+
+    #[test]
+    fn injects_triggered_key_events() {
+        let mut trellis = trellis();
+        trellis
+            .set_key_event_triggers(1, 2, &[KeyEventType::Pressed], true)
+            .unwrap();
+        trellis.enable_interrupt().unwrap();
+
+        let emulator = trellis.driver();
+        assert!(emulator.key_event(1, 2, KeyEventType::Pressed));
+        assert!(!emulator.key_event(1, 2, KeyEventType::Released));
+        assert!(!emulator.key_event(0, 0, KeyEventType::Pressed));
+        assert!(!emulator.key_event(8, 0, KeyEventType::Pressed));
+        assert!(!emulator.key_event(0, u8::MAX, KeyEventType::Pressed));
+        assert!(emulator.interrupt_pending());
+
+        let events = trellis.read_key_events().unwrap();
+        assert!(!events.overflowed());
+        assert_eq!(
+            events.collect::<Vec<_>>(),
+            [KeyEvent {
+                event: KeyEventType::Pressed,
+                x: 1,
+                y: 2
+            }]
+        );
+        assert_eq!(trellis.driver().pending_key_events(), 0);
+        assert!(!trellis.driver().interrupt_pending());
+    }
+
+    #[test]
+    fn stops_draining_a_refilling_fifo() {
+        let mut trellis = trellis();
+        trellis
+            .set_key_event_triggers(1, 1, &[KeyEventType::IsPressed], true)
+            .unwrap();
+        trellis.driver().hold_key(1, 1, true);
+        trellis.driver().push_key_event((1 << 3 | 1) << 2);
+
+        let mut events = Vec::new();
+        let drain = trellis.drain_key_events(|e| events.push(e)).unwrap();
+        assert!(drain.more_pending);
+        assert_eq!(drain.count, events.len());
+        assert!(events.iter().all(|e| e.event == KeyEventType::IsPressed));
+
+        trellis.driver().hold_key(1, 1, false);
+        let drain = trellis.drain_key_events(|_| {}).unwrap();
+        assert_eq!(drain.count, 1);
+        assert!(!drain.more_pending);
+    }
+
+    #[test]
+    fn reports_full_fifo() {
+        let mut trellis = trellis();
+        let raw = (3 << 3 | 3) << 2 | KeyEventType::Released as u8;
+        while trellis.driver().push_key_event(raw) {}
+
+        let events = trellis.read_key_events().unwrap();
+        assert!(events.overflowed());
+        assert_eq!(events.count(), 32);
+    }
+}
+
+#[cfg(feature = "device_rotary_encoder")]
+mod encoder {
+    use super::*;
+    use adafruit_seesaw::devices::RotaryEncoder;
+
+    fn encoder() -> RotaryEncoder<SeesawEmulator> {
+        let emulator = SeesawEmulator::for_device::<RotaryEncoder<SeesawEmulator>>();
+        RotaryEncoder::new_with_default_addr(emulator)
+            .init()
+            .unwrap()
+    }
+
+    #[test]
+    fn injects_turns() {
+        let mut encoder = encoder();
+        encoder.driver().turn_encoder(0, 3);
+        encoder.driver().turn_encoder(0, -1);
+        assert_eq!(encoder.position(0).unwrap(), 2);
+        assert_eq!(encoder.delta(0).unwrap(), 2);
+        assert_eq!(encoder.delta(0).unwrap(), 0);
+
+        encoder.set_position(0, -10).unwrap();
+        assert_eq!(encoder.driver().encoder_position(0), -10);
+    }
+
+    #[test]
+    fn interrupts_on_turns() {
+        let mut encoder = encoder();
+        encoder.enable_interrupt(0).unwrap();
+        assert!(encoder.driver().is_encoder_interrupt_enabled(0));
+        assert!(!encoder.driver().interrupt_pending());
+
+        encoder.driver().turn_encoder(0, 1);
+        assert!(encoder.driver().interrupt_pending());
+        encoder.delta(0).unwrap();
+        assert!(!encoder.driver().interrupt_pending());
+    }
+
+    #[test]
+    fn reads_button() {
+        let mut encoder = encoder();
+        assert!(!encoder.button(0).unwrap());
+        encoder.driver().set_pin_level(24, false);
+        assert!(encoder.button(0).unwrap());
+    }
+}
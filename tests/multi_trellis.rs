@@ -0,0 +1,52 @@
+#![cfg(all(feature = "mock", feature = "device_neotrellis"))]
+use adafruit_seesaw::{
+    devices::{MultiTrellis, NeoTrellis},
+    mock::{MockError, SeesawEmulator},
+    prelude::*,
+    SeesawError,
+};
+
+fn board(addr: u8) -> NeoTrellis<SeesawEmulator> {
+    let emulator = SeesawEmulator::for_device::<NeoTrellis<SeesawEmulator>>().with_addr(addr);
+    NeoTrellis::new(addr, emulator)
+}
+
+/// A raw keypad event for the key at local `(x, y)`
+fn pressed(x: u8, y: u8) -> u8 {
+    (y << 3 | x) << 2 | KeyEventType::Pressed as u8
+}
+
+#[test]
+fn merges_key_events_into_global_coordinates() {
+    let mut grid = MultiTrellis::new([[board(0x2E), board(0x2F)]]);
+    assert_eq!((grid.width(), grid.height()), (8, 4));
+    grid.board(0, 0).driver().push_key_event(pressed(1, 2));
+    grid.board(1, 0).driver().push_key_event(pressed(3, 0));
+
+    let events = grid.read_key_events().unwrap();
+    assert_eq!(
+        events.map(|e| (e.x, e.y)).collect::<Vec<_>>(),
+        [(1, 2), (7, 0)]
+    );
+}
+
+#[test]
+fn keeps_events_of_boards_read_before_and_after_an_error() {
+    let mut grid = MultiTrellis::new([[board(0x2E), board(0x2F), board(0x30)]]);
+    grid.board(0, 0).driver().push_key_event(pressed(0, 0));
+    grid.board(2, 0).driver().push_key_event(pressed(0, 1));
+    *grid.board(1, 0).driver() = SeesawEmulator::for_device::<NeoTrellis<SeesawEmulator>>();
+
+    let Err(partial) = grid.read_key_events() else {
+        panic!("expected the middle board to fail");
+    };
+    assert!(matches!(
+        partial.error,
+        SeesawError::I2c(MockError::Nack(0x2F))
+    ));
+    assert_eq!(
+        partial.events.map(|e| (e.x, e.y)).collect::<Vec<_>>(),
+        [(0, 0), (8, 1)]
+    );
+    assert_eq!(grid.board(2, 0).driver().pending_key_events(), 0);
+}
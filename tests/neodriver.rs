@@ -0,0 +1,108 @@
+#![cfg(all(feature = "mock", feature = "device_neodriver"))]
+use adafruit_seesaw::{
+    devices::NeoDriver,
+    framebuffer::Framebuffer,
+    mock::{MockError, SeesawEmulator},
+    prelude::*,
+    SeesawError,
+};
+use rgb::{RGB8, RGBA8};
+
+fn strip(len: usize) -> NeoDriver<SeesawEmulator> {
+    let emulator = SeesawEmulator::for_device::<NeoDriver<SeesawEmulator>>();
+    NeoDriver::new_with_default_addr(emulator)
+        .with_len(len)
+        .init()
+        .unwrap()
+}
+
+#[test]
+fn rejects_colors_past_end_of_strip() {
+    let mut strip = strip(3);
+    assert!(matches!(
+        strip.set_neopixel_colors_from(2, &[RGB8::new(1, 2, 3); 2]),
+        Err(SeesawError::NeopixelOutOfBounds { end: 4, len: 3 })
+    ));
+    assert!(matches!(
+        strip.set_nth_neopixel_color(3, RGB8::default()),
+        Err(SeesawError::NeopixelOutOfBounds { end: 4, len: 3 })
+    ));
+    assert_eq!(strip.driver().neopixel_buffer(), [0; 9]);
+
+    strip
+        .set_neopixel_colors_from(1, &[RGB8::new(1, 2, 3); 2])
+        .unwrap();
+    assert_eq!(
+        strip.driver().neopixel_buffer(),
+        [0, 0, 0, 2, 1, 3, 2, 1, 3]
+    );
+}
+
+#[test]
+fn rolls_back_failed_pin_change() {
+    let mut strip = strip(3);
+    strip.set_pin(4).unwrap();
+    assert_eq!(strip.driver().neopixel_pin(), Some(4));
+
+    let addr = strip.addr();
+    *strip.driver() = strip.driver().clone().with_addr(addr + 1);
+    assert!(matches!(
+        strip.set_pin(5),
+        Err(SeesawError::I2c(MockError::Nack(_)))
+    ));
+    assert_eq!(strip.neopixel_pin(), 4);
+}
+
+#[test]
+fn rolls_back_oversized_len() {
+    let mut strip = strip(3);
+    assert!(matches!(
+        strip.set_len(200),
+        Err(SeesawError::NeopixelBufferOverflow { len: 600, max: 512 })
+    ));
+    assert_eq!(strip.neopixel_len(), 3);
+    assert_eq!(strip.driver().neopixel_len(), 9);
+}
+
+#[test]
+fn rejects_oversized_strip_at_init() {
+    let emulator = SeesawEmulator::for_device::<NeoDriver<SeesawEmulator>>();
+    assert!(matches!(
+        NeoDriver::new_with_default_addr(emulator)
+            .with_len(129)
+            .with_color_order(ColorOrder::Grbw)
+            .init(),
+        Err(SeesawError::NeopixelBufferOverflow { len: 516, max: 512 })
+    ));
+}
+
+#[test]
+fn writes_in_configured_color_order() {
+    let mut strip = strip(2);
+    strip.set_color_order(ColorOrder::Grbw).unwrap();
+    assert_eq!(strip.driver().neopixel_len(), 8);
+
+    strip
+        .set_nth_neopixel_color(1, RGBA8::new(1, 2, 3, 4))
+        .unwrap();
+    assert_eq!(strip.driver().neopixel_buffer(), [0, 0, 0, 0, 2, 1, 3, 4]);
+}
+
+#[test]
+fn flushes_framebuffer() {
+    let mut fb = Framebuffer::<_, _, 2>::new(strip(2));
+    fb.set_pixel(1, RGB8::new(1, 2, 3)).unwrap();
+    fb.flush().unwrap();
+    assert_eq!(fb.device().driver().neopixel_shown(), [0, 0, 0, 2, 1, 3]);
+}
+
+#[test]
+fn rejects_framebuffer_pixels_past_end() {
+    let mut fb = Framebuffer::<_, _, 2>::new(strip(2));
+    assert!(matches!(
+        fb.set_pixel(2, RGB8::new(1, 2, 3)),
+        Err(SeesawError::NeopixelOutOfBounds { end: 3, len: 2 })
+    ));
+    assert_eq!(fb.get_pixel(2), None);
+    assert_eq!(fb.get_pixel(1), Some(RGBA8::default()));
+}